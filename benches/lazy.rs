@@ -0,0 +1,40 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use midasio::write::{EventBuilder, FileBuilder};
+use midasio::DataType;
+
+// A file with many small events, where the cost of allocating the data banks
+// of each event is most noticeable.
+fn file() -> Vec<u8> {
+    let events = (0..10_000u32).map(|i| {
+        EventBuilder::new(1)
+            .serial_number(i)
+            .bank(*b"ADC0", DataType::U16, vec![0; 64])
+            .bank(*b"ADC1", DataType::U16, vec![0; 64])
+            .bank(*b"TDC0", DataType::U32, vec![0; 32])
+    });
+    FileBuilder::new(1).events(events).build().unwrap()
+}
+
+fn eager_vs_lazy(c: &mut Criterion) {
+    let file = file();
+
+    let mut group = c.benchmark_group("events");
+    group.bench_function("try_events", |b| {
+        b.iter(|| {
+            midasio::try_events(black_box(&file))
+                .filter(|event_view| event_view.as_ref().unwrap().bank(b"TDC0").is_some())
+                .count()
+        })
+    });
+    group.bench_function("try_lazy_events", |b| {
+        b.iter(|| {
+            midasio::try_lazy_events(black_box(&file))
+                .filter(|event_view| event_view.as_ref().unwrap().bank(b"TDC0").is_some())
+                .count()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, eager_vs_lazy);
+criterion_main!(benches);
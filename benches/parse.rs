@@ -0,0 +1,89 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use midasio::write::{EventBuilder, FileBuilder};
+use midasio::{DataType, Endianness, FileView};
+
+const EVENTS: u32 = 5_000;
+
+// A run of typical events: a couple of ADC banks and a TDC bank each.
+fn file(endianness: Endianness) -> Vec<u8> {
+    let events = (0..EVENTS).map(|i| {
+        EventBuilder::new(1)
+            .serial_number(i)
+            .timestamp(i)
+            .bank(*b"ADC0", DataType::U16, vec![1; 512])
+            .bank(*b"ADC1", DataType::U16, vec![2; 512])
+            .bank(*b"TDC0", DataType::U32, vec![3; 256])
+    });
+    FileBuilder::new(1)
+        .endianness(endianness)
+        .initial_odb(vec![b' '; 4096])
+        .final_odb(vec![b' '; 4096])
+        .events(events)
+        .build()
+        .unwrap()
+}
+
+fn endianness_name(endianness: Endianness) -> &'static str {
+    match endianness {
+        Endianness::Little => "le",
+        Endianness::Big => "be",
+    }
+}
+
+fn parse(c: &mut Criterion) {
+    for endianness in [Endianness::Little, Endianness::Big] {
+        let file = file(endianness);
+        let name = endianness_name(endianness);
+
+        let mut group = c.benchmark_group("parse_bytes");
+        group.throughput(Throughput::Bytes(file.len() as u64));
+        group.bench_function(name, |b| {
+            b.iter(|| FileView::try_from_bytes(black_box(&file)).unwrap())
+        });
+        group.finish();
+
+        let mut group = c.benchmark_group("parse_events");
+        group.throughput(Throughput::Elements(EVENTS.into()));
+        group.bench_function(name, |b| {
+            b.iter(|| FileView::try_from_bytes(black_box(&file)).unwrap())
+        });
+        group.finish();
+    }
+}
+
+fn typed_decode(c: &mut Criterion) {
+    for endianness in [Endianness::Little, Endianness::Big] {
+        let file = file(endianness);
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+        let name = endianness_name(endianness);
+
+        let mut group = c.benchmark_group("values_u16");
+        group.throughput(Throughput::Elements(u64::from(EVENTS) * 512));
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                black_box(&file_view)
+                    .iter_banks()
+                    .filter_map(|bank_view| bank_view.values_u16(endianness))
+                    .flatten()
+                    .fold(0u64, |acc, value| acc + u64::from(value))
+            })
+        });
+        group.finish();
+
+        let mut group = c.benchmark_group("values_u32");
+        group.throughput(Throughput::Elements(u64::from(EVENTS) * 64));
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                black_box(&file_view)
+                    .iter_banks()
+                    .filter_map(|bank_view| bank_view.values_u32(endianness))
+                    .flatten()
+                    .fold(0u64, |acc, value| acc + u64::from(value))
+            })
+        });
+        group.finish();
+    }
+}
+
+criterion_group!(benches, parse, typed_decode);
+criterion_main!(benches);
@@ -0,0 +1,49 @@
+//! Magic numbers and header lengths of the MIDAS file format.
+//!
+//! A MIDAS file is laid out as:
+//!
+//! 1. An ODB dump header ([`ODB_HEADER_LENGTH`] bytes) with ID [`BOR_ID`],
+//!    followed by the initial ODB dump.
+//! 2. Zero or more events. Each event has an event header
+//!    ([`EVENT_HEADER_LENGTH`] bytes), an all-banks header
+//!    ([`ALL_BANKS_HEADER_LENGTH`] bytes), and its data banks. Each data bank
+//!    has a bank header (of [`BANK_16_HEADER_LENGTH`],
+//!    [`BANK_32_HEADER_LENGTH`], or [`BANK_32A_HEADER_LENGTH`] bytes) followed
+//!    by its data, padded to a multiple of [`BANK_DATA_ALIGNMENT`] bytes.
+//! 3. An ODB dump header with ID [`EOR_ID`], followed by the final ODB dump.
+//!
+//! All the multi-byte fields are stored in the byte order of the file.
+
+/// Event ID of the begin-of-run marker that precedes the initial ODB dump.
+pub const BOR_ID: u16 = 0x8000;
+/// Event ID of the end-of-run marker that precedes the final ODB dump.
+///
+/// Some DAQs also write an event with this ID and no data banks right before
+/// the final ODB dump; see
+/// [`EventView::is_end_of_run_marker`](crate::EventView::is_end_of_run_marker).
+pub const EOR_ID: u16 = 0x8001;
+/// Magic marker (the ASCII characters `"MI"` in little-endian files) that
+/// follows the [`BOR_ID`] and [`EOR_ID`] in an ODB dump header.
+pub const MAGIC: u16 = 0x494D;
+
+/// Length in bytes of an ODB dump header: the ID, the magic marker, the run
+/// number, the unix timestamp, and the size of the ODB dump.
+pub const ODB_HEADER_LENGTH: usize = 16;
+/// Length in bytes of an event header: the event ID, the trigger mask, the
+/// serial number, the unix timestamp, and the size of the event.
+pub const EVENT_HEADER_LENGTH: usize = 16;
+/// Length in bytes of the header that precedes the data banks of an event:
+/// the size of all the banks, and the bank flags.
+pub const ALL_BANKS_HEADER_LENGTH: usize = 8;
+/// Length in bytes of a 16-bit bank header: the name, the data type, and the
+/// size of the data.
+pub const BANK_16_HEADER_LENGTH: usize = 8;
+/// Length in bytes of a 32-bit bank header: the name, the data type, and the
+/// size of the data.
+pub const BANK_32_HEADER_LENGTH: usize = 12;
+/// Length in bytes of a 32-bit aligned bank header: the name, the data type,
+/// the size of the data, and 4 reserved bytes.
+pub const BANK_32A_HEADER_LENGTH: usize = 16;
+/// Alignment in bytes of the data in a bank. The data is padded to a multiple
+/// of this length.
+pub const BANK_DATA_ALIGNMENT: usize = 8;
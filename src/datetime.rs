@@ -0,0 +1,77 @@
+//! Conversions from the `u32` unix timestamps in a MIDAS file to date-time
+//! types.
+//!
+//! MIDAS timestamps are seconds since the unix epoch (1970-01-01 00:00:00 UTC)
+//! stored as an unsigned 32-bit integer; they wrap around in the year 2106.
+//! Every representable value converts losslessly.
+
+use crate::{EventView, FileView};
+
+#[cfg(feature = "chrono")]
+fn to_chrono(timestamp: u32) -> chrono::DateTime<chrono::Utc> {
+    // Any `u32` number of seconds is well within the range of `DateTime`.
+    chrono::DateTime::from_timestamp(timestamp.into(), 0).unwrap()
+}
+
+#[cfg(feature = "time")]
+fn to_time(timestamp: u32) -> time::OffsetDateTime {
+    // Any `u32` number of seconds is well within the range of
+    // `OffsetDateTime`.
+    time::OffsetDateTime::from_unix_timestamp(timestamp.into()).unwrap()
+}
+
+#[cfg(feature = "chrono")]
+impl EventView<'_> {
+    /// Returns the timestamp of the event as a [`chrono::DateTime`].
+    ///
+    /// See [`EventView::timestamp`].
+    pub fn datetime(&self) -> chrono::DateTime<chrono::Utc> {
+        to_chrono(self.timestamp())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl FileView<'_> {
+    /// Returns the timestamp of the initial ODB dump as a
+    /// [`chrono::DateTime`].
+    ///
+    /// See [`FileView::initial_timestamp`].
+    pub fn initial_datetime(&self) -> chrono::DateTime<chrono::Utc> {
+        to_chrono(self.initial_timestamp())
+    }
+    /// Returns the timestamp of the final ODB dump as a [`chrono::DateTime`].
+    ///
+    /// See [`FileView::final_timestamp`].
+    pub fn final_datetime(&self) -> chrono::DateTime<chrono::Utc> {
+        to_chrono(self.final_timestamp())
+    }
+}
+
+#[cfg(feature = "time")]
+impl EventView<'_> {
+    /// Returns the timestamp of the event as a [`time::OffsetDateTime`] in
+    /// UTC.
+    ///
+    /// See [`EventView::timestamp`].
+    pub fn offset_datetime(&self) -> time::OffsetDateTime {
+        to_time(self.timestamp())
+    }
+}
+
+#[cfg(feature = "time")]
+impl FileView<'_> {
+    /// Returns the timestamp of the initial ODB dump as a
+    /// [`time::OffsetDateTime`] in UTC.
+    ///
+    /// See [`FileView::initial_timestamp`].
+    pub fn initial_offset_datetime(&self) -> time::OffsetDateTime {
+        to_time(self.initial_timestamp())
+    }
+    /// Returns the timestamp of the final ODB dump as a
+    /// [`time::OffsetDateTime`] in UTC.
+    ///
+    /// See [`FileView::final_timestamp`].
+    pub fn final_offset_datetime(&self) -> time::OffsetDateTime {
+        to_time(self.final_timestamp())
+    }
+}
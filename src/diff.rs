@@ -0,0 +1,205 @@
+use crate::{EventView, FileView};
+use alloc::vec::Vec;
+use core::fmt;
+
+fn differ<T: PartialEq>(a: T, b: T) -> Option<(T, T)> {
+    (a != b).then_some((a, b))
+}
+
+/// Structural differences between two MIDAS files.
+///
+/// See [`FileView::diff`]. Each pair of values is ordered as `(self, other)`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct FileDiff {
+    /// Run numbers, if they are different.
+    pub run_number: Option<(u32, u32)>,
+    /// Timestamps of the initial ODB dumps, if they are different.
+    pub initial_timestamp: Option<(u32, u32)>,
+    /// Whether the initial ODB dumps are different.
+    pub initial_odb: bool,
+    /// Number of events, if they are different.
+    pub event_count: Option<(usize, usize)>,
+    /// Differences between the events at the same index in both files.
+    ///
+    /// Only the first [`FileDiff::MAX_EVENT_DIFFS`] events that differ are
+    /// reported.
+    pub events: Vec<EventDiff>,
+    /// Total number of events that differ, including the ones not reported in
+    /// [`FileDiff::events`].
+    pub total_event_diffs: usize,
+    /// Timestamps of the final ODB dumps, if they are different.
+    pub final_timestamp: Option<(u32, u32)>,
+    /// Whether the final ODB dumps are different.
+    pub final_odb: bool,
+}
+
+impl FileDiff {
+    /// Maximum number of event differences reported in [`FileDiff::events`].
+    pub const MAX_EVENT_DIFFS: usize = 10;
+
+    /// Returns `true` if no differences were found.
+    pub fn is_empty(&self) -> bool {
+        *self == FileDiff::default()
+    }
+}
+
+impl fmt::Display for FileDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "files are identical");
+        }
+        if let Some((a, b)) = self.run_number {
+            writeln!(f, "run number: `{a}` != `{b}`")?;
+        }
+        if let Some((a, b)) = self.initial_timestamp {
+            writeln!(f, "initial timestamp: `{a}` != `{b}`")?;
+        }
+        if self.initial_odb {
+            writeln!(f, "initial odb dumps differ")?;
+        }
+        if let Some((a, b)) = self.event_count {
+            writeln!(f, "event count: `{a}` != `{b}`")?;
+        }
+        for event_diff in &self.events {
+            writeln!(f, "{event_diff}")?;
+        }
+        if self.total_event_diffs > self.events.len() {
+            writeln!(
+                f,
+                "... and `{}` more events differ",
+                self.total_event_diffs - self.events.len()
+            )?;
+        }
+        if let Some((a, b)) = self.final_timestamp {
+            writeln!(f, "final timestamp: `{a}` != `{b}`")?;
+        }
+        if self.final_odb {
+            writeln!(f, "final odb dumps differ")?;
+        }
+        Ok(())
+    }
+}
+
+/// Differences between two events at the same index in two MIDAS files.
+///
+/// Each pair of values is ordered as `(self, other)`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct EventDiff {
+    /// Index of the event in both files.
+    pub index: usize,
+    /// Event IDs, if they are different.
+    pub id: Option<(u16, u16)>,
+    /// Trigger masks, if they are different.
+    pub trigger_mask: Option<(u16, u16)>,
+    /// Serial numbers, if they are different.
+    pub serial_number: Option<(u32, u32)>,
+    /// Timestamps, if they are different.
+    pub timestamp: Option<(u32, u32)>,
+    /// Number of data banks, if they are different.
+    pub bank_count: Option<(usize, usize)>,
+    /// Indices of the data banks (compared pairwise) that have a different
+    /// name, data type, or data.
+    pub banks: Vec<usize>,
+}
+
+impl EventDiff {
+    fn new(index: usize, a: &EventView, b: &EventView) -> Self {
+        EventDiff {
+            index,
+            id: differ(a.id(), b.id()),
+            trigger_mask: differ(a.trigger_mask(), b.trigger_mask()),
+            serial_number: differ(a.serial_number(), b.serial_number()),
+            timestamp: differ(a.timestamp(), b.timestamp()),
+            bank_count: differ(a.len(), b.len()),
+            banks: a
+                .iter()
+                .zip(b.iter())
+                .enumerate()
+                .filter(|(_, (a, b))| a != b)
+                .map(|(i, _)| i)
+                .collect(),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        *self
+            == EventDiff {
+                index: self.index,
+                ..Default::default()
+            }
+    }
+}
+
+impl fmt::Display for EventDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "event `{}`:", self.index)?;
+        if let Some((a, b)) = self.id {
+            write!(f, " id `{a}` != `{b}`;")?;
+        }
+        if let Some((a, b)) = self.trigger_mask {
+            write!(f, " trigger mask `{a}` != `{b}`;")?;
+        }
+        if let Some((a, b)) = self.serial_number {
+            write!(f, " serial number `{a}` != `{b}`;")?;
+        }
+        if let Some((a, b)) = self.timestamp {
+            write!(f, " timestamp `{a}` != `{b}`;")?;
+        }
+        if let Some((a, b)) = self.bank_count {
+            write!(f, " bank count `{a}` != `{b}`;")?;
+        }
+        if !self.banks.is_empty() {
+            write!(f, " banks {:?} differ;", self.banks)?;
+        }
+        Ok(())
+    }
+}
+
+impl FileView<'_> {
+    /// Returns the structural differences between this file and another.
+    ///
+    /// The file headers and ODB dumps are compared, and events are compared
+    /// pairwise by index. This is useful to explain why the outputs of two
+    /// data-processing pipelines differ.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let expected = std::fs::read("expected.mid")?;
+    /// let expected = midasio::FileView::try_from_bytes(&expected)?;
+    /// let actual = std::fs::read("actual.mid")?;
+    /// let actual = midasio::FileView::try_from_bytes(&actual)?;
+    ///
+    /// let diff = actual.diff(&expected);
+    /// assert!(diff.is_empty(), "{diff}");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn diff(&self, other: &FileView) -> FileDiff {
+        let mut events = Vec::new();
+        let mut total_event_diffs = 0;
+        for (index, (a, b)) in self.iter().zip(other.iter()).enumerate() {
+            let event_diff = EventDiff::new(index, a, b);
+            if !event_diff.is_empty() {
+                total_event_diffs += 1;
+                if events.len() < FileDiff::MAX_EVENT_DIFFS {
+                    events.push(event_diff);
+                }
+            }
+        }
+
+        FileDiff {
+            run_number: differ(self.run_number(), other.run_number()),
+            initial_timestamp: differ(self.initial_timestamp(), other.initial_timestamp()),
+            initial_odb: self.initial_odb() != other.initial_odb(),
+            event_count: differ(self.len(), other.len()),
+            events,
+            total_event_diffs,
+            final_timestamp: differ(self.final_timestamp(), other.final_timestamp()),
+            final_odb: self.final_odb() != other.final_odb(),
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use crate::constants::ODB_HEADER_LENGTH;
+use crate::{parse, Endianness, FileView, FileViewOptions, ParseError, ReadError};
+use std::io::Read;
+use std::ops::Range;
+use std::path::Path;
+use winnow::stream::Offset;
+use winnow::Parser;
+
+/// A MIDAS file read into memory.
+///
+/// A [`FileView`] borrows from the bytes it was created from, so the buffer
+/// has to be kept alive (and named) separately. A `File` owns the contents of
+/// the file instead, and [`File::view`] borrows from it.
+///
+/// A `File` is validated once when it is created, and it keeps the byte
+/// offsets of its events. Creating a view then only parses the data banks of
+/// each event.
+///
+/// A `File` dereferences to the raw bytes of the file.
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let file = midasio::File::open("example.mid")?;
+/// let file_view = file.view();
+///
+/// for event_view in &file_view {
+///     // Do something with each event in the file.
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct File {
+    bytes: Vec<u8>,
+    index: Index,
+}
+
+// Everything needed to create a `FileView` without validating the file again.
+#[derive(Clone, Debug)]
+struct Index {
+    run_number: u32,
+    initial_timestamp: u32,
+    initial_odb: Range<usize>,
+    event_offsets: Box<[usize]>,
+    final_run_number: u32,
+    final_timestamp: u32,
+    final_odb: Range<usize>,
+    endianness: Endianness,
+}
+
+impl Index {
+    fn new(bytes: &[u8]) -> Result<Self, ParseError> {
+        let file_view = FileView::try_from_bytes(bytes)?;
+        let initial_odb = ODB_HEADER_LENGTH..ODB_HEADER_LENGTH + file_view.initial_odb.len();
+        let final_odb_start = file_view.final_odb.offset_from(&bytes);
+        Ok(Index {
+            run_number: file_view.run_number,
+            initial_timestamp: file_view.initial_timestamp,
+            initial_odb,
+            event_offsets: file_view.iter().map(|e| e.byte_offset()).collect(),
+            final_run_number: file_view.final_run_number,
+            final_timestamp: file_view.final_timestamp,
+            final_odb: final_odb_start..final_odb_start + file_view.final_odb.len(),
+            endianness: file_view.endianness,
+        })
+    }
+}
+
+impl File {
+    /// Reads the entire contents of the file at the given path, and validates
+    /// it.
+    ///
+    /// With the `gzip` or `lz4` features enabled, compressed files are
+    /// detected from their magic bytes and decompressed transparently (see
+    /// [`read_gz_file`](crate::read_gz_file) and
+    /// [`read_lz4_file`](crate::read_lz4_file)).
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, ReadError> {
+        let bytes = std::fs::read(path)?;
+        #[cfg(feature = "gzip")]
+        let bytes = crate::gzip::maybe_decompress(bytes)?;
+        #[cfg(feature = "lz4")]
+        let bytes = crate::lz4::maybe_decompress(bytes)?;
+        Ok(File::try_from(bytes)?)
+    }
+    /// Reads a MIDAS file from a reader until EOF, and validates it.
+    ///
+    /// This is useful when the bytes of the file are not in a file system
+    /// (e.g. standard input or a network stream), and the whole file fits in
+    /// memory; see [`EventReader`](crate::EventReader) to read one event at a
+    /// time instead. Compressed files are handled as in [`File::open`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let file = midasio::File::from_reader(std::io::stdin().lock())?;
+    /// let file_view = file.view();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_reader<R: Read>(mut reader: R) -> Result<Self, ReadError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+        #[cfg(feature = "gzip")]
+        let bytes = crate::gzip::maybe_decompress(bytes)?;
+        #[cfg(feature = "lz4")]
+        let bytes = crate::lz4::maybe_decompress(bytes)?;
+        Ok(File::try_from(bytes)?)
+    }
+    /// Joins the parts of a MIDAS file that was split across multiple files
+    /// (e.g. `run00042.mid.000`, `run00042.mid.001`, etc.).
+    ///
+    /// The parts are concatenated, in the given order, into a single buffer;
+    /// this copies all their bytes. Only the first part starts with the initial
+    /// ODB dump, and only the last part ends with the final ODB dump. Returns
+    /// an error if the concatenated bytes are not a valid MIDAS file, with the
+    /// [`ParseError::offset`] relative to the beginning of the first part.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let first = std::fs::read("run00042.mid.000")?;
+    /// let last = std::fs::read("run00042.mid.001")?;
+    ///
+    /// let file = midasio::File::try_from_parts(&[&first, &last])?;
+    /// for event_view in &file.view() {
+    ///     // Do something with each event in the run.
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_from_parts(parts: &[&[u8]]) -> Result<Self, ParseError> {
+        File::try_from(parts.concat())
+    }
+    /// Returns a view to the file.
+    ///
+    /// The file was already validated when it was created, so this cannot
+    /// fail. Only the data banks of each event are parsed again, starting
+    /// from the event offsets found during validation.
+    pub fn view(&self) -> FileView<'_> {
+        let index = &self.index;
+        let event_views = index
+            .event_offsets
+            .iter()
+            .map(|&offset| {
+                // These bytes were already parsed successfully as an event, so
+                // parsing them again cannot fail.
+                let mut event_view = parse::event_view(index.endianness, FileViewOptions::new())
+                    .parse_next(&mut &self.bytes[offset..])
+                    .unwrap();
+                event_view.byte_offset = offset;
+                event_view
+            })
+            .collect();
+
+        FileView {
+            run_number: index.run_number,
+            initial_timestamp: index.initial_timestamp,
+            initial_odb: &self.bytes[index.initial_odb.clone()],
+            event_views,
+            final_run_number: index.final_run_number,
+            final_timestamp: index.final_timestamp,
+            final_odb: &self.bytes[index.final_odb.clone()],
+            has_final_odb: true,
+            endianness: index.endianness,
+        }
+    }
+    /// Returns the raw bytes of the file.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+impl TryFrom<Vec<u8>> for File {
+    type Error = ParseError;
+
+    /// Validates the bytes of a MIDAS file, and takes ownership of them.
+    fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+        let index = Index::new(&bytes)?;
+        Ok(File { bytes, index })
+    }
+}
+
+impl std::ops::Deref for File {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.bytes
+    }
+}
+
+impl AsRef<[u8]> for File {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
@@ -0,0 +1,41 @@
+use flate2::read::MultiGzDecoder;
+use std::io::Read;
+use std::path::Path;
+
+const GZIP_MAGIC: [u8; 2] = [0x1F, 0x8B];
+
+/// Reads the entire contents of a, possibly gzip-compressed, file into a
+/// bytes vector.
+///
+/// Gzip compression is detected from the magic bytes at the beginning of the
+/// file (not from the file extension), so an uncompressed MIDAS file is
+/// returned as is. Concatenated gzip members are decompressed as a single
+/// stream.
+///
+/// A [`FileView`](crate::FileView) borrows from the returned buffer, so the
+/// buffer must outlive it.
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let contents = midasio::read_gz_file("run00042.mid.gz")?;
+/// let file_view = midasio::FileView::try_from_bytes(&contents)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn read_gz_file<P: AsRef<Path>>(path: P) -> std::io::Result<Vec<u8>> {
+    maybe_decompress(std::fs::read(path)?)
+}
+
+// Decompress the contents of a file only if they start with the gzip magic
+// bytes.
+pub(crate) fn maybe_decompress(contents: Vec<u8>) -> std::io::Result<Vec<u8>> {
+    if !contents.starts_with(&GZIP_MAGIC) {
+        return Ok(contents);
+    }
+
+    let mut decompressed = Vec::new();
+    MultiGzDecoder::new(&contents[..]).read_to_end(&mut decompressed)?;
+    Ok(decompressed)
+}
@@ -0,0 +1,33 @@
+//! Parsing of JSON ODB dumps into [`serde_json::Value`]s.
+//!
+//! The ODB dump is parsed as-is; trailing NUL bytes and whitespace, which
+//! some versions of MIDAS write after the JSON document, are ignored.
+
+use crate::FileView;
+
+fn to_json(odb: &[u8]) -> Option<serde_json::Value> {
+    let len = odb
+        .iter()
+        .rposition(|&b| b != 0 && !b.is_ascii_whitespace())
+        .map_or(0, |i| i + 1);
+    serde_json::from_slice(&odb[..len]).ok()
+}
+
+impl FileView<'_> {
+    /// Returns the initial ODB dump parsed as JSON, or [`None`] if it is not
+    /// valid JSON (e.g. a legacy ASCII or XML dump).
+    ///
+    /// See [`FileView::initial_odb_format`] to find out the format of the
+    /// dump.
+    pub fn initial_odb_json(&self) -> Option<serde_json::Value> {
+        to_json(self.initial_odb())
+    }
+    /// Returns the final ODB dump parsed as JSON, or [`None`] if it is not
+    /// valid JSON (e.g. a legacy ASCII or XML dump).
+    ///
+    /// See [`FileView::final_odb_format`] to find out the format of the
+    /// dump.
+    pub fn final_odb_json(&self) -> Option<serde_json::Value> {
+        to_json(self.final_odb())
+    }
+}
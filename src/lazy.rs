@@ -0,0 +1,197 @@
+use crate::{parse, BankView, Endianness, EventView, FileViewOptions, ParseError};
+use core::iter::FusedIterator;
+use winnow::token::rest;
+use winnow::Parser;
+
+/// An immutable view to an event in a MIDAS file whose data banks are parsed
+/// on demand.
+///
+/// An [`EventView`] collects all of its [`BankView`]s into a heap-allocated
+/// slice when it is parsed. A `LazyEventView` is validated in exactly the same
+/// way, but it only keeps the raw bytes of its data banks; these are parsed
+/// again every time [`LazyEventView::banks`] is iterated. This avoids one
+/// allocation per event, which is worth it when most of the events are
+/// skipped (e.g. based on their ID) or when only a few banks of each event are
+/// read.
+///
+/// Lazy events are obtained with [`try_lazy_events`].
+#[derive(Clone, Copy, Debug)]
+pub struct LazyEventView<'a> {
+    id: u16,
+    trigger_mask: u16,
+    serial_number: u32,
+    timestamp: u32,
+    flags: u32,
+    banks: &'a [u8],
+    bytes: &'a [u8],
+    byte_offset: usize,
+    endianness: Endianness,
+}
+
+impl<'a> LazyEventView<'a> {
+    /// Returns the event ID.
+    pub fn id(&self) -> u16 {
+        self.id
+    }
+    /// Returns the trigger mask of the event.
+    pub fn trigger_mask(&self) -> u16 {
+        self.trigger_mask
+    }
+    /// Returns the serial number of the event.
+    pub fn serial_number(&self) -> u32 {
+        self.serial_number
+    }
+    /// Returns the unix timestamp of the event.
+    pub fn timestamp(&self) -> u32 {
+        self.timestamp
+    }
+    /// Returns the flags of the event.
+    ///
+    /// See [`EventView::flags`].
+    pub fn flags(&self) -> u32 {
+        self.flags
+    }
+    /// Returns the byte offset, from the beginning of the file, of the first
+    /// byte of the event (i.e. of its ID).
+    pub fn byte_offset(&self) -> usize {
+        self.byte_offset
+    }
+    /// Returns the byte order of the file the event belongs to.
+    pub fn endianness(&self) -> Endianness {
+        self.endianness
+    }
+    /// Returns an iterator over the data banks of the event, in the order in
+    /// which they appear in the file.
+    ///
+    /// Each data bank is parsed as the iterator advances.
+    pub fn banks(&self) -> LazyBanks<'a> {
+        LazyBanks {
+            input: self.banks,
+            flags: self.flags,
+            endianness: self.endianness,
+        }
+    }
+    /// Returns the first data bank in the event with the given name.
+    ///
+    /// Names are compared byte by byte, so names that are not valid UTF-8 are
+    /// also supported.
+    pub fn bank(&self, name: &[u8; 4]) -> Option<BankView<'a>> {
+        self.banks().find(|bank_view| bank_view.name() == *name)
+    }
+    /// Returns the raw bytes of all the data banks in the event.
+    ///
+    /// This is the contiguous region after the event header, including the
+    /// bank headers and padding, exactly as it appears in the file.
+    pub fn all_banks_bytes(&self) -> &'a [u8] {
+        self.banks
+    }
+    /// Returns the total number of bytes the event occupies in a MIDAS file.
+    pub fn size_bytes(&self) -> usize {
+        self.bytes.len()
+    }
+    /// Parses all the data banks into an [`EventView`].
+    pub fn to_event_view(&self) -> EventView<'a> {
+        EventView {
+            id: self.id,
+            trigger_mask: self.trigger_mask,
+            serial_number: self.serial_number,
+            timestamp: self.timestamp,
+            flags: self.flags,
+            bank_views: self.banks().collect(),
+            bytes: self.bytes,
+            byte_offset: self.byte_offset,
+            endianness: self.endianness,
+        }
+    }
+}
+
+impl<'a> IntoIterator for LazyEventView<'a> {
+    type Item = BankView<'a>;
+    type IntoIter = LazyBanks<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.banks()
+    }
+}
+
+/// An iterator over the data banks of a [`LazyEventView`].
+///
+/// This struct is created by [`LazyEventView::banks`].
+#[derive(Clone, Debug)]
+pub struct LazyBanks<'a> {
+    input: &'a [u8],
+    flags: u32,
+    endianness: Endianness,
+}
+
+impl<'a> Iterator for LazyBanks<'a> {
+    type Item = BankView<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.input.is_empty() {
+            return None;
+        }
+        // The banks were already validated when the event was parsed, so this
+        // never fails in practice. Just end the iteration if it does.
+        match parse::bank_view(self.endianness, self.flags, u32::MAX).parse_next(&mut self.input) {
+            Ok(bank_view) => Some(bank_view),
+            Err(_) => {
+                self.input = &[];
+                None
+            }
+        }
+    }
+}
+
+impl FusedIterator for LazyBanks<'_> {}
+
+/// Returns an iterator over the events of a MIDAS file, without collecting
+/// their data banks.
+///
+/// This is the same as [`try_events`](crate::try_events) (including which
+/// errors are yielded and when), but each event is a [`LazyEventView`] instead
+/// of an [`EventView`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let contents = std::fs::read("example.mid")?;
+///
+/// for event_view in midasio::try_lazy_events(&contents) {
+///     let event_view = event_view?;
+///     if event_view.id() == 1 {
+///         if let Some(bank_view) = event_view.bank(b"ADC0") {
+///             // Do something with the bank.
+///         }
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn try_lazy_events(
+    bytes: &[u8],
+) -> impl Iterator<Item = Result<LazyEventView<'_>, ParseError>> + '_ {
+    crate::try_events_with(bytes, |endianness, input, offset| {
+        let (event, remaining) = parse::parse_at(
+            (
+                parse::raw_event::<()>(endianness, FileViewOptions::new()),
+                rest,
+            ),
+            input,
+            offset,
+        )?;
+        let event_view = LazyEventView {
+            id: event.id,
+            trigger_mask: event.trigger_mask,
+            serial_number: event.serial_number,
+            timestamp: event.timestamp,
+            flags: event.flags,
+            banks: event.banks,
+            bytes: event.bytes,
+            byte_offset: offset,
+            endianness,
+        };
+        Ok((event_view, remaining))
+    })
+}
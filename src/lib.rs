@@ -1,27 +1,230 @@
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![doc = include_str!("../README.md")]
 
-use winnow::binary::u32;
-use winnow::combinator::{delimited, rest};
-use winnow::error::{ContextError, PResult, StrContext};
-use winnow::token::take;
+#[cfg(not(feature = "alloc"))]
+compile_error!("at least one of the `std` or `alloc` features must be enabled");
+
+extern crate alloc;
+
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use constants::{
+    ALL_BANKS_HEADER_LENGTH, BANK_16_HEADER_LENGTH, BANK_32A_HEADER_LENGTH, BANK_32_HEADER_LENGTH,
+    BANK_DATA_ALIGNMENT, EOR_ID, EVENT_HEADER_LENGTH, ODB_HEADER_LENGTH,
+};
+use core::mem::size_of;
+use winnow::combinator::{delimited, preceded, terminated};
+use winnow::error::{ContextError, ModalResult, StrContext};
+use winnow::token::{rest, take};
 use winnow::Parser;
 
 #[cfg(feature = "rayon")]
-use rayon::iter::IntoParallelRefIterator;
-
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+pub use diff::{EventDiff, FileDiff};
+#[cfg(feature = "std")]
+pub use file::File;
+#[cfg(feature = "gzip")]
+pub use gzip::read_gz_file;
+pub use lazy::{try_lazy_events, LazyBanks, LazyEventView};
+#[cfg(feature = "lz4")]
+pub use lz4::read_lz4_file;
+#[cfg(feature = "mmap")]
+pub use mmap::MmapFile;
+#[cfg(feature = "std")]
+pub use reader::{EventReader, ReadError};
+
+pub mod constants;
+#[cfg(any(feature = "chrono", feature = "time"))]
+mod datetime;
+mod diff;
+#[cfg(feature = "std")]
+mod file;
+#[cfg(feature = "gzip")]
+mod gzip;
+#[cfg(feature = "serde_json")]
+mod json;
+mod lazy;
+#[cfg(feature = "lz4")]
+mod lz4;
+#[cfg(feature = "mmap")]
+mod mmap;
+pub mod odb;
 mod parse;
+#[cfg(feature = "std")]
+mod reader;
+pub mod write;
+
+// Iterator over the values in a data bank decoded as `$num_type`.
+macro_rules! bank_values {
+    ($data:expr, $endianness:expr, $num_type:ty) => {{
+        let endianness = $endianness;
+        $data
+            .chunks_exact(core::mem::size_of::<$num_type>())
+            .map(move |chunk| {
+                let bytes = chunk.try_into().unwrap();
+                match endianness {
+                    Endianness::Little => <$num_type>::from_le_bytes(bytes),
+                    Endianness::Big => <$num_type>::from_be_bytes(bytes),
+                }
+            })
+    }};
+    ($bank_view:expr, $num_type:ty) => {
+        bank_values!($bank_view.data, $bank_view.endianness, $num_type)
+    };
+}
 
 /// The error type returned when parsing a MIDAS file fails.
 #[derive(Debug)]
 pub struct ParseError {
     offset: usize,
     inner: ContextError,
+    event_index: Option<usize>,
+    bank_name: Option<[u8; 4]>,
+}
+
+impl ParseError {
+    /// Returns the byte offset, from the beginning of the input, at which
+    /// parsing stopped.
+    ///
+    /// See [`ParseError::hexdump_context`] to inspect the bytes around this
+    /// offset.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+    /// Returns a [`hexdump`] of (at most) `len` bytes of the input centred on
+    /// the [`offset`](ParseError::offset) at which parsing stopped.
+    ///
+    /// The `bytes` should be the same input that produced this error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// // Invalid magic marker after the begin-of-run id.
+    /// let bytes = b"\x00\x80XY\x01\x00\x00\x00";
+    ///
+    /// let error = midasio::FileView::try_from_bytes(bytes).unwrap_err();
+    /// assert_eq!(error.offset(), 2);
+    /// assert_eq!(
+    ///     error.hexdump_context(bytes, 4),
+    ///     "00000000  00 80 58 59                                       |..XY|\n"
+    /// );
+    /// ```
+    pub fn hexdump_context(&self, bytes: &[u8], len: usize) -> String {
+        hexdump(bytes, self.offset.saturating_sub(len / 2), len)
+    }
+    /// Returns the zero-based index of the invalid event, or [`None`] if the
+    /// error is not within the events of the file.
+    pub fn event_index(&self) -> Option<usize> {
+        self.event_index
+    }
+    /// Returns the name of the first invalid data bank in the invalid event,
+    /// or [`None`] if it is not known.
+    pub fn bank_name(&self) -> Option<[u8; 4]> {
+        self.bank_name
+    }
+    /// Returns the kind of error.
+    ///
+    /// This is the most specific reason known for the failure; e.g. a data
+    /// bank that exceeds [`FileViewOptions::max_bank_data_size`] is reported as
+    /// [`ParseErrorKind::SizeExceeded`]. Otherwise, an invalid event is
+    /// reported as [`ParseErrorKind::InvalidBank`] whenever the invalid data
+    /// bank within it is known (see [`ParseError::bank_name`]).
+    pub fn kind(&self) -> ParseErrorKind {
+        let kind = self.inner.context().find_map(|context| match context {
+            StrContext::Label(label) => ParseErrorKind::from_label(label),
+            _ => None,
+        });
+        match kind {
+            Some(kind) => kind,
+            None if self.bank_name.is_some() => ParseErrorKind::InvalidBank,
+            None if self.event_index.is_some() => ParseErrorKind::InvalidEvent,
+            None => ParseErrorKind::Other,
+        }
+    }
+}
+
+/// The kind of error that makes a MIDAS file invalid.
+///
+/// See [`ParseError::kind`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// The file does not start with a begin-of-run ID in either byte order.
+    BadBorId,
+    /// The bytes after the last event are not an end-of-run ID.
+    BadEorId,
+    /// The magic marker in the header of an ODB dump is invalid.
+    BadMagic,
+    /// The run numbers in the initial and final ODB dumps are different.
+    RunNumberMismatch,
+    /// There are bytes after the final ODB dump.
+    TrailingBytes,
+    /// An ODB dump is shorter than its declared size.
+    OdbSizeMismatch,
+    /// The sizes in the header of an event are inconsistent with each other or
+    /// with its data banks.
+    EventSizeMismatch,
+    /// The flags of an event are not a known [`BankType`].
+    UnknownFlags,
+    /// The flags of an event are only valid in the opposite byte order, i.e. the
+    /// event was written with a different byte order than the begin-of-run ID.
+    EndiannessInconsistent,
+    /// A data bank is invalid, e.g. it has an unknown data type, or its data is
+    /// not a multiple of the size of its data type.
+    InvalidBank,
+    /// An event is invalid, for a reason not covered by any other kind.
+    InvalidEvent,
+    /// A declared size exceeds a limit set in the [`FileViewOptions`].
+    SizeExceeded,
+    /// The input ends in the middle of a header.
+    Truncated,
+    /// Any other error.
+    Other,
+}
+
+impl ParseErrorKind {
+    fn from_label(label: &str) -> Option<Self> {
+        let kind = match label {
+            "begin-of-run id" => ParseErrorKind::BadBorId,
+            "end-of-run id" => ParseErrorKind::BadEorId,
+            "end of file" => ParseErrorKind::TrailingBytes,
+            "initial magic marker" | "final magic marker" => ParseErrorKind::BadMagic,
+            "final run number" => ParseErrorKind::RunNumberMismatch,
+            "initial odb dump" | "final odb dump" => ParseErrorKind::OdbSizeMismatch,
+            "event size" | "size of all banks" => ParseErrorKind::EventSizeMismatch,
+            "maximum event size" | "maximum bank data size" => ParseErrorKind::SizeExceeded,
+            "bank data type" | "bank data" => ParseErrorKind::InvalidBank,
+            "bank flags" => ParseErrorKind::UnknownFlags,
+            "bank flags (byte order inconsistent with the begin-of-run id)" => {
+                ParseErrorKind::EndiannessInconsistent
+            }
+            "magic marker"
+            | "magic marker and run number"
+            | "run number"
+            | "initial run number"
+            | "initial timestamp"
+            | "initial unix timestamp"
+            | "final unix timestamp"
+            | "event" => ParseErrorKind::Truncated,
+            _ => return None,
+        };
+        Some(kind)
+    }
 }
 
-impl std::fmt::Display for ParseError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "parsing stopped at byte offset `{}`", self.offset)?;
+        if let Some(event_index) = self.event_index {
+            write!(f, " in event `{event_index}`")?;
+        }
+        if let Some(bank_name) = self.bank_name {
+            write!(f, " at bank `{}`", bank_name.escape_ascii())?;
+        }
         if self.inner.context().next().is_some() {
             write!(f, " ({})", self.inner)?;
         }
@@ -29,6 +232,7 @@ impl std::fmt::Display for ParseError {
     }
 }
 
+#[cfg(feature = "std")]
 impl std::error::Error for ParseError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         self.inner
@@ -37,9 +241,172 @@ impl std::error::Error for ParseError {
     }
 }
 
-/// Possible data types stored inside a data bank.
+/// The error type returned when decoding a value from the data of a bank fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DecodeError {
+    offset: usize,
+    len: usize,
+}
+
+impl DecodeError {
+    /// Returns the byte offset, from the beginning of the bank data, of the
+    /// value that could not be decoded.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl core::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "incomplete value of `{}` bytes at byte offset `{}`",
+            self.len, self.offset
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeError {}
+
+/// Description of the damage found in a MIDAS file that was parsed leniently.
+///
+/// See [`FileView::try_from_bytes_lenient`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Recovery {
+    offset: usize,
+    discarded: usize,
+    final_odb: bool,
+}
+
+impl Recovery {
+    /// Returns the byte offset, from the beginning of the input, at which
+    /// parsing stopped.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+    /// Returns the number of bytes, starting at [`Recovery::offset`], that
+    /// were ignored.
+    pub fn discarded_bytes(&self) -> usize {
+        self.discarded
+    }
+    /// Returns `true` if a valid final ODB dump was found.
+    ///
+    /// If `false`, the final timestamp of the recovered file is zero and its
+    /// final ODB dump is empty.
+    pub fn has_final_odb(&self) -> bool {
+        self.final_odb
+    }
+}
+
+/// The error type returned when a MIDAS file does not match an expected schema.
+///
+/// See [`FileView::validate_schema`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SchemaError {
+    event_index: usize,
+    kind: SchemaErrorKind,
+}
+
+impl SchemaError {
+    /// Returns the index of the first event that does not match the schema.
+    pub fn event_index(&self) -> usize {
+        self.event_index
+    }
+    /// Returns the kind of mismatch found in the event.
+    pub fn kind(&self) -> SchemaErrorKind {
+        self.kind
+    }
+}
+
+impl core::fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "event `{}` does not match the schema (",
+            self.event_index
+        )?;
+        match self.kind {
+            SchemaErrorKind::MissingBank { name } => {
+                write!(f, "missing bank `{}`", name.escape_ascii())?;
+            }
+            SchemaErrorKind::ExtraBank { name } => {
+                write!(f, "unexpected bank `{}`", name.escape_ascii())?;
+            }
+            SchemaErrorKind::WrongDataType {
+                name,
+                expected,
+                found,
+            } => {
+                write!(
+                    f,
+                    "bank `{}` has data type `{found:?}` instead of `{expected:?}`",
+                    name.escape_ascii()
+                )?;
+            }
+        }
+        write!(f, ")")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SchemaError {}
+
+/// The kind of mismatch between an event and an expected schema.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[non_exhaustive]
+pub enum SchemaErrorKind {
+    /// An expected data bank is not present in the event.
+    MissingBank {
+        /// Name of the missing data bank.
+        name: [u8; 4],
+    },
+    /// The event has a data bank that is not part of the schema.
+    ExtraBank {
+        /// Name of the unexpected data bank.
+        name: [u8; 4],
+    },
+    /// A data bank does not have the expected data type.
+    WrongDataType {
+        /// Name of the data bank.
+        name: [u8; 4],
+        /// Data type in the schema.
+        expected: DataType,
+        /// Data type found in the event.
+        found: DataType,
+    },
+}
+
+/// Byte order of the values in a MIDAS file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Endianness {
+    /// Least significant byte first.
+    Little,
+    /// Most significant byte first.
+    Big,
+}
+
+impl Endianness {
+    /// Returns the byte order of the target platform.
+    pub const fn native() -> Self {
+        if cfg!(target_endian = "little") {
+            Endianness::Little
+        } else {
+            Endianness::Big
+        }
+    }
+    /// Returns `true` if the byte order matches the one of the target platform.
+    ///
+    /// Values stored in this byte order can be reinterpreted in place, without
+    /// swapping bytes.
+    pub fn matches_host(&self) -> bool {
+        *self == Endianness::native()
+    }
+}
+
+/// Possible data types stored inside a data bank.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum DataType {
     /// Unsigned byte.
     U8,
@@ -69,30 +436,762 @@ pub enum DataType {
     I64,
     /// Unsigned 64-bits integer.
     U64,
+    /// Key of the online database (ODB), stored as a NUL-terminated string.
+    Key,
+    /// Link to a key of the online database (ODB), stored as the
+    /// NUL-terminated path of the target key.
+    Link,
+}
+
+impl DataType {
+    /// Returns the data type of the banks that hold values of the Rust type
+    /// `T`.
+    ///
+    /// This is the same as [`T::DATA_TYPE`](MidasScalar::DATA_TYPE).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use midasio::DataType;
+    ///
+    /// assert_eq!(DataType::from_rust_type::<u32>(), DataType::U32);
+    /// assert_eq!(DataType::from_rust_type::<f64>(), DataType::F64);
+    /// ```
+    pub const fn from_rust_type<T: MidasScalar>() -> DataType {
+        T::DATA_TYPE
+    }
+    /// Returns the size in bytes of a single value of the data type.
+    ///
+    /// Returns [`None`] for data types without a fixed size i.e. strings (including
+    /// keys and links), arrays, and structures.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use midasio::DataType;
+    ///
+    /// assert_eq!(DataType::U16.size(), Some(2));
+    /// assert_eq!(DataType::Bool.size(), Some(4));
+    /// assert_eq!(DataType::Str.size(), None);
+    /// ```
+    pub fn size(&self) -> Option<usize> {
+        match self {
+            DataType::U8 => Some(size_of::<u8>()),
+            DataType::I8 => Some(size_of::<i8>()),
+            DataType::U16 => Some(size_of::<u16>()),
+            DataType::I16 => Some(size_of::<i16>()),
+            DataType::U32 => Some(size_of::<u32>()),
+            DataType::I32 => Some(size_of::<i32>()),
+            DataType::Bool => Some(4),
+            DataType::F32 => Some(size_of::<f32>()),
+            DataType::F64 => Some(size_of::<f64>()),
+            DataType::Str => None,
+            DataType::Array => None,
+            DataType::Struct => None,
+            DataType::I64 => Some(size_of::<i64>()),
+            DataType::U64 => Some(size_of::<u64>()),
+            DataType::Key => None,
+            DataType::Link => None,
+        }
+    }
+    /// Returns the signedness of an integer data type.
+    ///
+    /// Returns [`None`] if the data type is not an integer e.g. floating-point
+    /// numbers, booleans, strings, etc.
+    pub fn signedness(&self) -> Option<Signedness> {
+        match self {
+            DataType::I8 | DataType::I16 | DataType::I32 | DataType::I64 => {
+                Some(Signedness::Signed)
+            }
+            DataType::U8 | DataType::U16 | DataType::U32 | DataType::U64 => {
+                Some(Signedness::Unsigned)
+            }
+            DataType::Bool
+            | DataType::F32
+            | DataType::F64
+            | DataType::Str
+            | DataType::Array
+            | DataType::Struct
+            | DataType::Key
+            | DataType::Link => None,
+        }
+    }
+    /// Returns `true` if the data type is a signed or unsigned integer.
+    pub fn is_integer(&self) -> bool {
+        self.signedness().is_some()
+    }
+    /// Returns `true` if the data type is a signed integer or a floating-point
+    /// number.
+    pub fn is_signed(&self) -> bool {
+        self.signedness() == Some(Signedness::Signed) || self.is_floating()
+    }
+    /// Returns `true` if the data type is a floating-point number.
+    pub fn is_floating(&self) -> bool {
+        matches!(self, DataType::F32 | DataType::F64)
+    }
+}
+
+/// Formats the data type with the name of its data type ID (`TID_*`) as
+/// defined by MIDAS, e.g. `TID_UINT32` for [`DataType::U32`]. Use the [`Debug`]
+/// implementation for the name of the Rust variant instead.
+impl core::fmt::Display for DataType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let name = match self {
+            DataType::U8 => "TID_UINT8",
+            DataType::I8 => "TID_INT8",
+            DataType::U16 => "TID_UINT16",
+            DataType::I16 => "TID_INT16",
+            DataType::U32 => "TID_UINT32",
+            DataType::I32 => "TID_INT32",
+            DataType::Bool => "TID_BOOL",
+            DataType::F32 => "TID_FLOAT",
+            DataType::F64 => "TID_DOUBLE",
+            DataType::Str => "TID_STRING",
+            DataType::Array => "TID_ARRAY",
+            DataType::Struct => "TID_STRUCT",
+            DataType::I64 => "TID_INT64",
+            DataType::U64 => "TID_UINT64",
+            DataType::Key => "TID_KEY",
+            DataType::Link => "TID_LINK",
+        };
+        f.write_str(name)
+    }
+}
+
+/// The error type returned when a checked conversion from an unsigned integer
+/// to a [`DataType`] fails.
+///
+/// The valid integers are the data type IDs (`TID_*`) defined by MIDAS.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TryDataTypeFromUnsignedError(());
+
+impl core::fmt::Display for TryDataTypeFromUnsignedError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "unknown midas data type id")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TryDataTypeFromUnsignedError {}
+
+/// Signedness of an integer [`DataType`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Signedness {
+    /// Signed integer.
+    Signed,
+    /// Unsigned integer.
+    Unsigned,
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A numeric Rust type that corresponds to a [`DataType`].
+///
+/// This trait is sealed; it is implemented for all the numeric types that have
+/// a [`DataType`]. Generic code can use it to know the data type of a bank at
+/// compile time e.g. `<u32 as MidasScalar>::DATA_TYPE`.
+pub trait MidasScalar: sealed::Sealed {
+    /// Data type of the banks that hold values of this type.
+    const DATA_TYPE: DataType;
+}
+
+/// A numeric type that the values of a data bank can be decoded as.
+///
+/// This trait is sealed; it is implemented for all the [`MidasScalar`] types.
+/// See [`BankView::iter_as`].
+pub trait FromBankBytes: MidasScalar + Sized {
+    /// Decodes a value from its byte representation with the given byte
+    /// order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `bytes` is not the size of `Self`.
+    fn from_bank_bytes(bytes: &[u8], endianness: Endianness) -> Self;
+}
+
+macro_rules! impl_from_bank_bytes {
+    ($($num_type:ty => $data_type:ident),+ $(,)?) => {
+        $(
+            impl sealed::Sealed for $num_type {}
+
+            impl MidasScalar for $num_type {
+                const DATA_TYPE: DataType = DataType::$data_type;
+            }
+
+            impl FromBankBytes for $num_type {
+                fn from_bank_bytes(bytes: &[u8], endianness: Endianness) -> Self {
+                    let bytes = bytes.try_into().unwrap();
+                    match endianness {
+                        Endianness::Little => <$num_type>::from_le_bytes(bytes),
+                        Endianness::Big => <$num_type>::from_be_bytes(bytes),
+                    }
+                }
+            }
+        )+
+    };
+}
+impl_from_bank_bytes!(
+    u8 => U8,
+    i8 => I8,
+    u16 => U16,
+    i16 => I16,
+    u32 => U32,
+    i32 => I32,
+    f32 => F32,
+    f64 => F64,
+    i64 => I64,
+    u64 => U64,
+);
+
+/// Best guess of the format of an ODB dump.
+///
+/// See [`OdbFormat::detect`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OdbFormat {
+    /// XML dump, starting with `<`.
+    Xml,
+    /// JSON dump, starting with `{` or `[`.
+    Json,
+    /// Legacy ASCII dump, starting with a `[/...]` section header.
+    ///
+    /// See [`odb::ascii::parse_ascii_odb`].
+    Ascii,
+    /// Empty, binary, or otherwise unrecognized dump.
+    Unknown,
+}
+
+impl OdbFormat {
+    /// Guesses the format of an ODB dump from its leading bytes.
+    ///
+    /// Leading whitespace and a UTF-8 byte order mark are ignored. This only
+    /// looks at the first significant byte(s), so it never fails; it does not
+    /// validate the rest of the dump.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use midasio::OdbFormat;
+    ///
+    /// assert_eq!(OdbFormat::detect(b"<?xml version=\"1.0\"?>"), OdbFormat::Xml);
+    /// assert_eq!(OdbFormat::detect(b"{ \"/MIDAS version\" : \"2.1\" }"), OdbFormat::Json);
+    /// assert_eq!(OdbFormat::detect(b"[/System]"), OdbFormat::Ascii);
+    /// assert_eq!(OdbFormat::detect(b""), OdbFormat::Unknown);
+    /// ```
+    pub fn detect(odb: &[u8]) -> Self {
+        let odb = odb.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(odb);
+        let mut bytes = odb.iter().copied().filter(|b| !b.is_ascii_whitespace());
+        match (bytes.next(), bytes.next()) {
+            (Some(b'<'), _) => OdbFormat::Xml,
+            (Some(b'['), Some(b'/')) => OdbFormat::Ascii,
+            (Some(b'{' | b'['), _) => OdbFormat::Json,
+            _ => OdbFormat::Unknown,
+        }
+    }
+}
+
+/// Format of the headers of the data banks in an event.
+///
+/// All the data banks in an event have the same format, which is determined
+/// by the flags of the event (see [`EventView::flags`]).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BankType {
+    /// 16-bit bank size and data type ID (event flags `1`).
+    B16,
+    /// 32-bit bank size and data type ID (event flags `17`).
+    B32,
+    /// 32-bit bank size and data type ID, with a reserved 32-bit field that
+    /// keeps the data 64-bit aligned (event flags `49`).
+    B32A,
+}
+
+impl BankType {
+    /// Returns the event flags that correspond to this bank format.
+    pub fn to_flag(self) -> u32 {
+        match self {
+            BankType::B16 => 1,
+            BankType::B32 => 17,
+            BankType::B32A => 49,
+        }
+    }
+    /// Returns the size in bytes of the header of a data bank in this format.
+    pub fn header_size(self) -> usize {
+        match self {
+            BankType::B16 => BANK_16_HEADER_LENGTH,
+            BankType::B32 => BANK_32_HEADER_LENGTH,
+            BankType::B32A => BANK_32A_HEADER_LENGTH,
+        }
+    }
 }
 
+impl TryFrom<u32> for BankType {
+    type Error = TryBankTypeFromFlagsError;
+
+    fn try_from(flags: u32) -> Result<Self, Self::Error> {
+        match flags {
+            1 => Ok(BankType::B16),
+            17 => Ok(BankType::B32),
+            49 => Ok(BankType::B32A),
+            _ => Err(TryBankTypeFromFlagsError(())),
+        }
+    }
+}
+
+/// The error type returned when a checked conversion from event flags to a
+/// [`BankType`] fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TryBankTypeFromFlagsError(());
+
+impl core::fmt::Display for TryBankTypeFromFlagsError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "unknown midas event flags")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TryBankTypeFromFlagsError {}
+
 /// An immutable view to a data bank in a MIDAS file.
+///
+/// Two data banks are equal if they have the same name, data type, and data
+/// (compared by value). The padding bytes after the data, the bank header
+/// format, and the byte order of the file are not part of the comparison.
 #[derive(Clone, Copy, Debug)]
 pub struct BankView<'a> {
     name: [u8; 4],
     data_type: DataType,
+    data_type_raw: u32,
     data: &'a [u8],
+    bank_type: BankType,
+    endianness: Endianness,
+}
+
+impl PartialEq for BankView<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.data_type == other.data_type && self.data == other.data
+    }
+}
+
+impl Eq for BankView<'_> {}
+
+impl core::hash::Hash for BankView<'_> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.data_type.hash(state);
+        self.data.hash(state);
+    }
 }
 
 impl<'a> BankView<'a> {
+    /// Creates a little-endian data bank with the given name, data type, and
+    /// data, without parsing it from a MIDAS file.
+    ///
+    /// This is mostly useful to test code that consumes [`BankView`]s. Returns
+    /// an error if the length of the data is not a multiple of the
+    /// [size](DataType::size) of the data type, or if the data is too large to
+    /// be represented in the MIDAS format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use midasio::{BankView, DataType, Endianness};
+    ///
+    /// let bank_view = BankView::new(*b"ADC0", DataType::U16, &[1, 0, 2, 0])?;
+    /// let values: Vec<u16> = bank_view.values_u16(Endianness::Little).unwrap().collect();
+    /// assert_eq!(values, [1, 2]);
+    /// # Ok::<(), midasio::write::WriteError>(())
+    /// ```
+    pub fn new(
+        name: [u8; 4],
+        data_type: DataType,
+        data: &'a [u8],
+    ) -> Result<Self, write::WriteError> {
+        if !data.len().is_multiple_of(data_type.size().unwrap_or(1)) {
+            return Err(write::WriteError::InvalidBankData {
+                name,
+                data_type,
+                len: data.len(),
+            });
+        }
+        // Same choice of bank header as `write::EventBuilder`.
+        let bank_type = if u16::try_from(data.len()).is_ok() {
+            BankType::B16
+        } else if u32::try_from(data.len()).is_ok() {
+            BankType::B32A
+        } else {
+            return Err(write::WriteError::TooLarge);
+        };
+        Ok(BankView {
+            name,
+            data_type,
+            data_type_raw: write::tid(data_type).into(),
+            data,
+            bank_type,
+            endianness: Endianness::Little,
+        })
+    }
     /// Returns the name of the data bank.
+    ///
+    /// The name is not validated in any way; names with punctuation,
+    /// spaces, or even non-ASCII bytes are returned as they appear in the
+    /// file.
     pub fn name(&self) -> [u8; 4] {
         self.name
     }
+    /// Returns the name of the data bank as a string slice, or an error if it
+    /// is not valid UTF-8.
+    pub fn name_str(&self) -> Result<&str, core::str::Utf8Error> {
+        core::str::from_utf8(&self.name)
+    }
+    /// Returns the name of the data bank as a string.
+    ///
+    /// Invalid UTF-8 sequences are replaced with
+    /// [`U+FFFD REPLACEMENT CHARACTER`](core::char::REPLACEMENT_CHARACTER).
+    pub fn name_str_lossy(&self) -> Cow<'_, str> {
+        String::from_utf8_lossy(&self.name)
+    }
     /// Returns the data type of the data bank.
     pub fn data_type(&self) -> DataType {
         self.data_type
     }
+    /// Returns the data type ID (`TID_*`) exactly as read from the bank
+    /// header.
+    ///
+    /// Multiple IDs can map to the same [`DataType`] (e.g. both `TID_CHAR`
+    /// and `TID_UINT8` are [`DataType::U8`]); use this to distinguish them.
+    pub fn data_type_raw(&self) -> u32 {
+        self.data_type_raw
+    }
     /// Returns the data stored in the data bank.
     pub fn data(&self) -> &'a [u8] {
         self.data
     }
+    /// Splits the data of the bank into a payload and a trailer of the last `n`
+    /// bytes, or returns [`None`] if `n` is larger than the data.
+    ///
+    /// This is useful for banks that carry e.g. a checksum after their
+    /// payload.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use midasio::BankView;
+    /// # fn crc32(bytes: &[u8]) -> u32 { unimplemented!() }
+    /// # fn f(bank_view: BankView) {
+    /// if let Some((payload, trailer)) = bank_view.data_split_last(4) {
+    ///     let crc = u32::from_le_bytes(trailer.try_into().unwrap());
+    ///     assert_eq!(crc32(payload), crc);
+    /// }
+    /// # }
+    /// ```
+    pub fn data_split_last(&self, n: usize) -> Option<(&'a [u8], &'a [u8])> {
+        let mid = self.data.len().checked_sub(n)?;
+        Some(self.data.split_at(mid))
+    }
+    /// Returns the number of values in the data bank.
+    ///
+    /// This is the length of [`BankView::data`] divided by the
+    /// [size](DataType::size) of the data type. For data types without a fixed
+    /// size (e.g. strings), this is the length of the data in bytes.
+    pub fn data_len_elements(&self) -> usize {
+        self.data.len() / self.data_type.size().unwrap_or(1)
+    }
+    /// Returns the byte order of the file the data bank belongs to.
+    ///
+    /// This is the byte order needed to decode the values in
+    /// [`BankView::data`].
+    pub fn endianness(&self) -> Endianness {
+        self.endianness
+    }
+    /// Returns the format of the header of the data bank.
+    pub fn bank_type(&self) -> BankType {
+        self.bank_type
+    }
+    /// Returns the data of a [`DataType::Str`], [`DataType::Key`], or
+    /// [`DataType::Link`] bank as a string, with trailing NUL bytes removed.
+    ///
+    /// Returns [`None`] if the bank does not have one of these data types, or
+    /// if the data is not valid UTF-8. See [`BankView::as_str_lossy`] for a
+    /// lossy alternative.
+    pub fn as_str(&self) -> Option<&'a str> {
+        if !self.has_string_data() {
+            return None;
+        }
+        let len = self.data.iter().rposition(|&b| b != 0).map_or(0, |i| i + 1);
+        core::str::from_utf8(&self.data[..len]).ok()
+    }
+    /// Returns an iterator over the NUL-terminated strings in a
+    /// [`DataType::Str`], [`DataType::Key`], or [`DataType::Link`] bank.
+    ///
+    /// The data is split on NUL bytes and empty strings (e.g. from NUL padding)
+    /// are skipped. Each item is [`None`] if that string is not valid UTF-8.
+    /// Returns [`None`] if the bank does not have one of these data types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use midasio::BankView;
+    /// # fn strings(bank_view: BankView) {
+    /// // A bank with data `b"foo\0\0\0bar\0\0\0"`.
+    /// let strings: Vec<_> = bank_view.strings().unwrap().collect();
+    /// assert_eq!(strings, [Some("foo"), Some("bar")]);
+    /// # }
+    /// ```
+    pub fn strings(&self) -> Option<impl Iterator<Item = Option<&'a str>> + 'a> {
+        if !self.has_string_data() {
+            return None;
+        }
+        Some(
+            self.data
+                .split(|&b| b == 0)
+                .filter(|s| !s.is_empty())
+                .map(|s| core::str::from_utf8(s).ok()),
+        )
+    }
+    /// Returns the data of a [`DataType::Str`], [`DataType::Key`], or
+    /// [`DataType::Link`] bank as a string, with trailing NUL bytes removed.
+    ///
+    /// Invalid UTF-8 sequences are replaced with
+    /// [`U+FFFD REPLACEMENT CHARACTER`](core::char::REPLACEMENT_CHARACTER).
+    /// Returns [`None`] if the bank does not have one of these data types.
+    pub fn as_str_lossy(&self) -> Option<Cow<'a, str>> {
+        if !self.has_string_data() {
+            return None;
+        }
+        let len = self.data.iter().rposition(|&b| b != 0).map_or(0, |i| i + 1);
+        Some(String::from_utf8_lossy(&self.data[..len]))
+    }
+    /// Returns all the values in the bank decoded according to its
+    /// [`DataType`], type-erased as a [`Box<dyn Any + Send>`](core::any::Any).
+    ///
+    /// The concrete type behind the [`Any`](core::any::Any) is:
+    ///
+    /// | Data type | Concrete type |
+    /// |-----------|---------------|
+    /// | [`DataType::U8`], [`DataType::Array`], [`DataType::Struct`] | `Vec<u8>` |
+    /// | [`DataType::I8`] | `Vec<i8>` |
+    /// | [`DataType::U16`] | `Vec<u16>` |
+    /// | [`DataType::I16`] | `Vec<i16>` |
+    /// | [`DataType::U32`] | `Vec<u32>` |
+    /// | [`DataType::I32`] | `Vec<i32>` |
+    /// | [`DataType::Bool`] | `Vec<bool>` |
+    /// | [`DataType::F32`] | `Vec<f32>` |
+    /// | [`DataType::F64`] | `Vec<f64>` |
+    /// | [`DataType::Str`], [`DataType::Key`], [`DataType::Link`] | `String` (see [`BankView::as_str_lossy`]) |
+    /// | [`DataType::I64`] | `Vec<i64>` |
+    /// | [`DataType::U64`] | `Vec<u64>` |
+    ///
+    /// This is meant for pipelines where the handling of banks is chosen at
+    /// runtime. Note that every call copies all the data into a new heap
+    /// allocation, and then boxes it; prefer decoding [`BankView::data`]
+    /// directly when the type is known at compile time.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let contents = std::fs::read("example.mid")?;
+    /// # let file_view = midasio::FileView::try_from_bytes(&contents)?;
+    /// # let bank_view = file_view.iter().next().unwrap().iter().next().unwrap();
+    /// let values = bank_view.decode_any();
+    /// if let Some(values) = values.downcast_ref::<Vec<u32>>() {
+    ///     println!("sum: {}", values.iter().sum::<u32>());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn decode_any(&self) -> Box<dyn core::any::Any + Send> {
+        match self.data_type {
+            DataType::U8 | DataType::Array | DataType::Struct => Box::new(self.data.to_vec()),
+            DataType::I8 => Box::new(bank_values!(self, i8).collect::<Vec<_>>()),
+            DataType::U16 => Box::new(bank_values!(self, u16).collect::<Vec<_>>()),
+            DataType::I16 => Box::new(bank_values!(self, i16).collect::<Vec<_>>()),
+            DataType::U32 => Box::new(bank_values!(self, u32).collect::<Vec<_>>()),
+            DataType::I32 => Box::new(bank_values!(self, i32).collect::<Vec<_>>()),
+            DataType::Bool => Box::new(bank_values!(self, u32).map(|n| n != 0).collect::<Vec<_>>()),
+            DataType::F32 => Box::new(bank_values!(self, f32).collect::<Vec<_>>()),
+            DataType::F64 => Box::new(bank_values!(self, f64).collect::<Vec<_>>()),
+            DataType::Str | DataType::Key | DataType::Link => {
+                Box::new(self.as_str_lossy().unwrap().into_owned())
+            }
+            DataType::I64 => Box::new(bank_values!(self, i64).collect::<Vec<_>>()),
+            DataType::U64 => Box::new(bank_values!(self, u64).collect::<Vec<_>>()),
+        }
+    }
+    /// Returns an iterator over the fixed-size records in the data of the bank,
+    /// each decoded with the given function.
+    ///
+    /// This is meant for [`DataType::Struct`] and [`DataType::Array`] banks
+    /// (or any other bank with a user-defined layout), where the size of each
+    /// record is `stride` bytes. A trailing partial record is ignored, and the
+    /// iterator is empty if `stride` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use midasio::BankView;
+    /// # fn parse_struct(bank_view: BankView) {
+    /// // Each record is a little-endian `(u16 channel, u32 value)` pair.
+    /// let records: Vec<(u16, u32)> = bank_view
+    ///     .parse_struct(6, |record| {
+    ///         let channel = u16::from_le_bytes(record[..2].try_into().unwrap());
+    ///         let value = u32::from_le_bytes(record[2..].try_into().unwrap());
+    ///         (channel, value)
+    ///     })
+    ///     .collect();
+    /// # }
+    /// ```
+    pub fn parse_struct<T, F>(&self, stride: usize, f: F) -> impl Iterator<Item = T> + 'a
+    where
+        F: FnMut(&'a [u8]) -> T + 'a,
+    {
+        let data = if stride == 0 { &[] } else { self.data };
+        data.chunks_exact(stride.max(1)).map(f)
+    }
+    /// Returns an iterator that decodes the data of the bank as `u32` values,
+    /// regardless of its [`DataType`].
+    ///
+    /// Each value is decoded independently; if the length of the data is not a
+    /// multiple of 4 bytes, the last item is an error for the trailing partial
+    /// value. This is useful to salvage banks with a mislabeled data type.
+    pub fn try_iter_u32(&self) -> impl Iterator<Item = Result<u32, DecodeError>> + 'a {
+        let endianness = self.endianness;
+        self.data.chunks(4).enumerate().map(move |(i, chunk)| {
+            let bytes = chunk.try_into().map_err(|_| DecodeError {
+                offset: 4 * i,
+                len: chunk.len(),
+            })?;
+            Ok(match endianness {
+                Endianness::Little => u32::from_le_bytes(bytes),
+                Endianness::Big => u32::from_be_bytes(bytes),
+            })
+        })
+    }
+    /// Returns an iterator over the values in the bank decoded as `T`, with
+    /// the given byte order.
+    ///
+    /// Returns [`None`] if the data type of the bank is not
+    /// [`T::DATA_TYPE`](MidasScalar::DATA_TYPE).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use midasio::{BankView, Endianness};
+    /// # fn f(bank_view: BankView) {
+    /// if let Some(values) = bank_view.iter_as::<u16>(Endianness::Little) {
+    ///     let sum: u64 = values.map(u64::from).sum();
+    /// }
+    /// # }
+    /// ```
+    pub fn iter_as<T: FromBankBytes>(
+        &self,
+        endianness: Endianness,
+    ) -> Option<impl Iterator<Item = T> + 'a> {
+        (self.data_type == T::DATA_TYPE).then(|| {
+            self.data
+                .chunks_exact(size_of::<T>())
+                .map(move |chunk| T::from_bank_bytes(chunk, endianness))
+        })
+    }
+    /// Returns an iterator over the values of a [`DataType::Bool`] bank, with
+    /// the given byte order.
+    ///
+    /// Returns [`None`] if the data type of the bank is not
+    /// [`DataType::Bool`]. Note that MIDAS booleans are 4 bytes long (not 1),
+    /// and any non-zero value is `true`.
+    pub fn values_bool(&self, endianness: Endianness) -> Option<impl Iterator<Item = bool> + 'a> {
+        (self.data_type == DataType::Bool).then(|| {
+            self.data
+                .chunks_exact(4)
+                .map(move |chunk| u32::from_bank_bytes(chunk, endianness) != 0)
+        })
+    }
+    /// Reinterprets the data of the bank as a slice of `T`, without copying
+    /// it.
+    ///
+    /// Returns [`None`] if the byte order of the bank is not the one of the
+    /// target platform (see [`Endianness::matches_host`]), if the size of `T`
+    /// is not the [size](DataType::size) of the data type of the bank, or if
+    /// the data is not correctly aligned for `T`. Use [`BankView::iter_as`]
+    /// to decode the values in any of these cases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use midasio::BankView;
+    /// # fn f(bank_view: BankView) {
+    /// let values: Vec<u16> = match bank_view.cast_slice::<u16>() {
+    ///     Some(values) => values.to_vec(),
+    ///     None => bank_view.iter_as(bank_view.endianness()).unwrap().collect(),
+    /// };
+    /// # }
+    /// ```
+    #[cfg(feature = "bytemuck")]
+    pub fn cast_slice<T: bytemuck::Pod>(&self) -> Option<&'a [T]> {
+        if !self.endianness.matches_host() || self.data_type.size() != Some(size_of::<T>()) {
+            return None;
+        }
+        bytemuck::try_cast_slice(self.data).ok()
+    }
+    /// Returns the number of padding bytes that follow the data of the bank.
+    ///
+    /// The data of a bank is always padded to a multiple of 8 bytes. This is
+    /// also the number of bytes consumed after the data while parsing; a file
+    /// with any other amount of bytes between banks fails to parse.
+    pub fn required_padding(&self) -> usize {
+        self.data.len().next_multiple_of(BANK_DATA_ALIGNMENT) - self.data.len()
+    }
+    /// Returns the total number of bytes the bank occupies in a MIDAS file.
+    ///
+    /// This includes the bank header, the data, and the padding.
+    pub fn total_on_disk_size(&self) -> usize {
+        self.bank_type.header_size() + self.data.len() + self.required_padding()
+    }
+
+    fn has_string_data(&self) -> bool {
+        matches!(
+            self.data_type,
+            DataType::Str | DataType::Key | DataType::Link
+        )
+    }
+}
+
+macro_rules! impl_bank_view_values {
+    ($($method:ident => ($num_type:ty, $data_type:ident)),+ $(,)?) => {
+        impl<'a> BankView<'a> {
+            $(
+                #[doc = concat!(
+                    "Returns an iterator over the values in a [`DataType::",
+                    stringify!($data_type),
+                    "`] bank, decoded with the given byte order.\n\n",
+                    "Returns [`None`] if the data type of the bank is not [`DataType::",
+                    stringify!($data_type),
+                    "`]."
+                )]
+                pub fn $method(
+                    &self,
+                    endianness: Endianness,
+                ) -> Option<impl Iterator<Item = $num_type> + 'a> {
+                    self.iter_as(endianness)
+                }
+            )+
+        }
+    };
 }
+impl_bank_view_values!(
+    values_u8 => (u8, U8),
+    values_i8 => (i8, I8),
+    values_u16 => (u16, U16),
+    values_i16 => (i16, I16),
+    values_u32 => (u32, U32),
+    values_i32 => (i32, I32),
+    values_f32 => (f32, F32),
+    values_f64 => (f64, F64),
+    values_i64 => (i64, I64),
+    values_u64 => (u64, U64),
+);
 
 /// An immutable view to an event in a MIDAS file.
 ///
@@ -103,7 +1202,11 @@ pub struct EventView<'a> {
     trigger_mask: u16,
     serial_number: u32,
     timestamp: u32,
+    flags: u32,
     bank_views: Box<[BankView<'a>]>,
+    bytes: &'a [u8],
+    byte_offset: usize,
+    endianness: Endianness,
 }
 
 impl<'a> EventView<'a> {
@@ -123,15 +1226,188 @@ impl<'a> EventView<'a> {
     pub fn timestamp(&self) -> u32 {
         self.timestamp
     }
+    /// Returns the flags of the event.
+    ///
+    /// These determine the format of the headers of its data banks: `1` for
+    /// 16-bit headers, `17` for 32-bit headers, and `49` for 32-bit headers
+    /// with 64-bit aligned data.
+    pub fn flags(&self) -> u32 {
+        self.flags
+    }
+    /// Returns the format of the headers of the data banks in the event.
+    pub fn bank_type(&self) -> BankType {
+        // The flags are validated when the event is parsed.
+        BankType::try_from(self.flags).unwrap_or(BankType::B16)
+    }
+    /// Returns the byte offset, from the beginning of the file, of the first
+    /// byte of the event (i.e. of its ID).
+    ///
+    /// This is useful to build an index of the events in a file.
+    pub fn byte_offset(&self) -> usize {
+        self.byte_offset
+    }
+    /// Returns the byte order of the file the event belongs to.
+    pub fn endianness(&self) -> Endianness {
+        self.endianness
+    }
     /// Returns an iterator over the data banks of the event.
-    pub fn iter(&self) -> std::slice::Iter<'_, BankView<'a>> {
+    ///
+    /// All the data banks are parsed up front, so the iterator is an
+    /// [`ExactSizeIterator`] and a [`DoubleEndedIterator`].
+    pub fn iter(&self) -> core::slice::Iter<'_, BankView<'a>> {
         self.into_iter()
     }
+    /// Returns `true` if this is an end-of-run marker event, i.e. an event
+    /// with ID [`EOR_ID`] and no data banks.
+    ///
+    /// Some DAQs write such an event right before the final ODB dump. It is
+    /// parsed as a regular event, and it is not mistaken for the final ODB
+    /// dump as long as its trigger mask is not the MIDAS magic marker.
+    pub fn is_end_of_run_marker(&self) -> bool {
+        self.id == EOR_ID && self.bank_views.is_empty()
+    }
+    /// Returns the data banks of the event, in the order in which they appear
+    /// in the file.
+    pub fn banks(&self) -> &[BankView<'a>] {
+        &self.bank_views
+    }
+    /// Returns the data bank at the given index, or [`None`] if the index is
+    /// out of bounds.
+    pub fn get(&self, index: usize) -> Option<&BankView<'a>> {
+        self.bank_views.get(index)
+    }
+    /// Returns the number of data banks in the event.
+    pub fn len(&self) -> usize {
+        self.bank_views.len()
+    }
+    /// Returns `true` if the event has no data banks.
+    pub fn is_empty(&self) -> bool {
+        self.bank_views.is_empty()
+    }
+    /// Returns the first data bank in the event with the given name.
+    ///
+    /// Names are compared byte by byte, so names that are not valid UTF-8 are
+    /// also supported.
+    pub fn bank(&self, name: &[u8; 4]) -> Option<&BankView<'a>> {
+        self.bank_views
+            .iter()
+            .find(|bank_view| bank_view.name() == *name)
+    }
+    /// Returns `true` if the event has at least one data bank with the given
+    /// name.
+    ///
+    /// This is the same as `self.bank(name).is_some()`.
+    pub fn contains_bank(&self, name: &[u8; 4]) -> bool {
+        self.bank(name).is_some()
+    }
+    /// Returns an iterator over all the data banks in the event with the given
+    /// name.
+    pub fn banks_named<'b>(&'b self, name: &'b [u8; 4]) -> impl Iterator<Item = &'b BankView<'a>> {
+        self.bank_views
+            .iter()
+            .filter(move |bank_view| bank_view.name() == *name)
+    }
+    /// Returns an iterator over the data banks of the event paired with their
+    /// byte offset from the beginning of the event.
+    ///
+    /// Each offset points at the first byte of the bank (i.e. of its name).
+    /// Add [`EventView::byte_offset`] to get the offset from the beginning of
+    /// the file.
+    pub fn bank_offsets(&self) -> impl Iterator<Item = (&BankView<'a>, usize)> {
+        self.bank_views.iter().scan(
+            EVENT_HEADER_LENGTH + ALL_BANKS_HEADER_LENGTH,
+            |offset, bank_view| {
+                let bank_offset = *offset;
+                *offset += bank_view.total_on_disk_size();
+                Some((bank_view, bank_offset))
+            },
+        )
+    }
+    /// Returns an iterator over the data banks in the event together with the
+    /// padding bytes that follow each of them in the file.
+    ///
+    /// The padding is returned verbatim (it is not necessarily zeroed), which
+    /// allows re-emitting the banks byte by byte.
+    pub fn banks_with_padding(&self) -> impl Iterator<Item = (&BankView<'a>, &'a [u8])> {
+        let bytes = self.bytes;
+        self.bank_offsets().map(move |(bank_view, offset)| {
+            let start = offset + bank_view.bank_type.header_size() + bank_view.data.len();
+            (
+                bank_view,
+                &bytes[start..start + bank_view.required_padding()],
+            )
+        })
+    }
+    /// Returns the total number of bytes the event occupies in a MIDAS file.
+    ///
+    /// This includes the 24-byte event header and, for every data bank, its
+    /// header, data, and padding.
+    pub fn size_bytes(&self) -> usize {
+        EVENT_HEADER_LENGTH
+            + ALL_BANKS_HEADER_LENGTH
+            + self.iter().map(BankView::total_on_disk_size).sum::<usize>()
+    }
+    /// Returns an object that implements [`Display`](core::fmt::Display) to
+    /// print a one-line summary of the header of the event.
+    ///
+    /// Formatting does not allocate. Use the [`Debug`](core::fmt::Debug)
+    /// implementation of the event for a full dump instead.
+    pub fn display_summary(&self) -> EventSummaryDisplay<'_, 'a> {
+        EventSummaryDisplay(self)
+    }
+    /// Returns a summary of the header of the event.
+    pub fn metadata(&self) -> EventMetadata {
+        EventMetadata {
+            id: self.id,
+            trigger_mask: self.trigger_mask,
+            serial_number: self.serial_number,
+            timestamp: self.timestamp,
+            flags: self.flags,
+            bank_count: self.bank_views.len(),
+            payload_len: self.bytes.len() - EVENT_HEADER_LENGTH - ALL_BANKS_HEADER_LENGTH,
+        }
+    }
+    /// Returns the raw bytes of all the data banks in the event.
+    ///
+    /// This is the contiguous region after the event header, including the
+    /// bank headers and padding, exactly as it appears in the file.
+    pub fn all_banks_bytes(&self) -> &'a [u8] {
+        &self.bytes[EVENT_HEADER_LENGTH + ALL_BANKS_HEADER_LENGTH..]
+    }
+    /// Returns the data of all the banks with the given name, in the same order
+    /// as they appear in the event.
+    ///
+    /// This is useful to collect the fragments of a payload that was split
+    /// across multiple banks with the same name.
+    pub fn bank_slices(&self, name: [u8; 4]) -> Vec<&'a [u8]> {
+        self.banks_named(&name)
+            .map(|bank_view| bank_view.data())
+            .collect()
+    }
+    /// Returns an [`OwnedEvent`] with a copy of the raw bytes of the event.
+    ///
+    /// See [`OwnedEvent`] for the trade-offs of owning the raw bytes instead of
+    /// borrowing them.
+    pub fn to_owned_event(&self) -> OwnedEvent {
+        OwnedEvent {
+            bytes: self.bytes.into(),
+            byte_offset: self.byte_offset,
+            endianness: self.endianness,
+        }
+    }
+}
+
+impl<'a> core::ops::Index<usize> for EventView<'a> {
+    type Output = BankView<'a>;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.bank_views[index]
+    }
 }
 
 impl<'a, 'b> IntoIterator for &'b EventView<'a> {
     type Item = &'b BankView<'a>;
-    type IntoIter = std::slice::Iter<'b, BankView<'a>>;
+    type IntoIter = core::slice::Iter<'b, BankView<'a>>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.bank_views.iter()
@@ -140,65 +1416,827 @@ impl<'a, 'b> IntoIterator for &'b EventView<'a> {
 
 impl<'a> IntoIterator for EventView<'a> {
     type Item = BankView<'a>;
-    type IntoIter = std::vec::IntoIter<BankView<'a>>;
+    type IntoIter = alloc::vec::IntoIter<BankView<'a>>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.bank_views.into_vec().into_iter()
     }
 }
 
-/// An immutable view to a MIDAS file.
-///
-/// A file is a collection of [`EventView`]s wrapped by two dumps of the Online
-/// DataBase (ODB) at the beginning and end of the sub-run.
-#[derive(Clone, Debug)]
-pub struct FileView<'a> {
-    run_number: u32,
-    initial_timestamp: u32,
-    initial_odb: &'a [u8],
-    event_views: Box<[EventView<'a>]>,
-    final_timestamp: u32,
-    final_odb: &'a [u8],
+#[cfg(feature = "rayon")]
+impl<'a> rayon::iter::IntoParallelIterator for EventView<'a> {
+    type Item = BankView<'a>;
+    type Iter = rayon::vec::IntoIter<BankView<'a>>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.bank_views.into_vec().into_par_iter()
+    }
 }
 
-impl<'a> FileView<'a> {
-    /// Create a native view to the underlying file from its representation as a
-    /// byte slice.
-    pub fn try_from_bytes(bytes: &'a [u8]) -> Result<Self, ParseError> {
-        parse::file_view.parse(bytes).map_err(|e| ParseError {
-            offset: e.offset(),
-            inner: e.into_inner(),
-        })
+#[cfg(feature = "rayon")]
+impl<'a, 'b> rayon::iter::IntoParallelIterator for &'b EventView<'a> {
+    type Item = &'b BankView<'a>;
+    type Iter = rayon::slice::Iter<'b, BankView<'a>>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.bank_views.par_iter()
     }
-    /// Returns the run number of the file.
-    pub fn run_number(&self) -> u32 {
-        self.run_number
+}
+
+/// Summary of the header of an event in a MIDAS file.
+///
+/// See [`EventView::metadata`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EventMetadata {
+    /// Event ID.
+    pub id: u16,
+    /// Trigger mask of the event.
+    pub trigger_mask: u16,
+    /// Serial number of the event.
+    pub serial_number: u32,
+    /// Unix timestamp of the event.
+    pub timestamp: u32,
+    /// Flags of the event. These determine the format of the data banks.
+    pub flags: u32,
+    /// Number of data banks in the event.
+    pub bank_count: usize,
+    /// Size in bytes of all the data banks in the event (including their
+    /// headers and padding).
+    pub payload_len: usize,
+}
+
+/// Summary of the contents of a MIDAS file.
+///
+/// See [`FileView::summary`].
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileSummary {
+    /// Run number of the file.
+    pub run_number: u32,
+    /// Unix timestamp of the initial ODB dump.
+    pub initial_timestamp: u32,
+    /// Unix timestamp of the final ODB dump, or zero if the file has no final
+    /// ODB dump.
+    pub final_timestamp: u32,
+    /// Number of events in the file.
+    pub event_count: usize,
+    /// Number of data banks across all the events in the file.
+    pub bank_count: usize,
+    /// Number of data banks with each name across all the events in the file.
+    pub bank_name_counts: std::collections::HashMap<[u8; 4], usize>,
+    /// Data types of all the data banks in the file.
+    pub data_types: std::collections::HashSet<DataType>,
+    /// Size in bytes of the data of all the data banks in the file (excluding
+    /// their headers and padding).
+    pub data_len: usize,
+}
+
+/// Helper struct to print a one-line summary of the header of an event with
+/// `format!` and `{}`.
+///
+/// The output has the form `id=1 mask=0x0001 serial=42 ts=1700000000
+/// banks=3`. See [`EventView::display_summary`].
+#[derive(Clone, Copy, Debug)]
+pub struct EventSummaryDisplay<'b, 'a>(&'b EventView<'a>);
+
+impl core::fmt::Display for EventSummaryDisplay<'_, '_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "id={} mask={:#06x} serial={} ts={} banks={}",
+            self.0.id,
+            self.0.trigger_mask,
+            self.0.serial_number,
+            self.0.timestamp,
+            self.0.bank_views.len()
+        )
     }
-    /// Returns the unix timestamp of the initial ODB dump.
-    pub fn initial_timestamp(&self) -> u32 {
-        self.initial_timestamp
+}
+
+/// Helper struct to print a one-line summary of the header of a MIDAS file
+/// with `format!` and `{}`.
+///
+/// The output has the form `run=7 ts=1700000000 final_ts=1700003600
+/// events=1000`. See [`FileView::display_summary`].
+#[derive(Clone, Copy, Debug)]
+pub struct FileSummaryDisplay<'b, 'a>(&'b FileView<'a>);
+
+impl core::fmt::Display for FileSummaryDisplay<'_, '_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "run={} ts={} final_ts={} events={}",
+            self.0.run_number,
+            self.0.initial_timestamp,
+            self.0.final_timestamp,
+            self.0.event_views.len()
+        )
+    }
+}
+
+/// An owned event from a MIDAS file.
+///
+/// An [`EventView`] borrows from the underlying file, so it cannot outlive the
+/// bytes it was parsed from. An `OwnedEvent` instead holds a copy of the raw
+/// bytes of the event. Creating one is just a memory copy, nothing is decoded
+/// up front; the cost is that the data banks are parsed again every time
+/// [`OwnedEvent::view`] is called.
+#[derive(Clone, Debug)]
+pub struct OwnedEvent {
+    bytes: Box<[u8]>,
+    byte_offset: usize,
+    endianness: Endianness,
+}
+
+impl OwnedEvent {
+    /// Returns the event ID.
+    pub fn id(&self) -> u16 {
+        parse::u16(self.endianness)
+            .parse_peek(&self.bytes[..])
+            .unwrap()
+            .1
+    }
+    /// Returns the trigger mask of the event.
+    pub fn trigger_mask(&self) -> u16 {
+        parse::u16(self.endianness)
+            .parse_peek(&self.bytes[2..])
+            .unwrap()
+            .1
+    }
+    /// Returns the serial number of the event.
+    pub fn serial_number(&self) -> u32 {
+        parse::u32(self.endianness)
+            .parse_peek(&self.bytes[4..])
+            .unwrap()
+            .1
+    }
+    /// Returns the unix timestamp of the event.
+    pub fn timestamp(&self) -> u32 {
+        parse::u32(self.endianness)
+            .parse_peek(&self.bytes[8..])
+            .unwrap()
+            .1
+    }
+    /// Returns the byte offset, from the beginning of the file, of the first
+    /// byte of the event.
+    ///
+    /// See [`EventView::byte_offset`].
+    pub fn byte_offset(&self) -> usize {
+        self.byte_offset
+    }
+    /// Returns the raw bytes of the event.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+    /// Parses the raw bytes of the event again and returns a view to it.
+    pub fn view(&self) -> EventView<'_> {
+        // These bytes were already parsed successfully as an event when this
+        // was created, so parsing them again cannot fail.
+        let mut event_view = parse::event_view(self.endianness, FileViewOptions::new())
+            .parse(&self.bytes)
+            .unwrap();
+        event_view.byte_offset = self.byte_offset;
+        event_view
+    }
+}
+
+/// An immutable view to a MIDAS file.
+///
+/// A file is a collection of [`EventView`]s wrapped by two dumps of the Online
+/// DataBase (ODB) at the beginning and end of the sub-run.
+#[derive(Clone, Debug)]
+pub struct FileView<'a> {
+    run_number: u32,
+    initial_timestamp: u32,
+    initial_odb: &'a [u8],
+    event_views: Box<[EventView<'a>]>,
+    final_run_number: u32,
+    final_timestamp: u32,
+    final_odb: &'a [u8],
+    has_final_odb: bool,
+    endianness: Endianness,
+}
+
+/// Options to configure how strictly a MIDAS file is parsed.
+///
+/// See [`FileView::try_from_bytes_with`]. The default options are the ones
+/// used by [`FileView::try_from_bytes`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use midasio::{FileView, FileViewOptions};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let contents = std::fs::read("live_dump.mid")?;
+/// let options = FileViewOptions::new().require_final_odb(false);
+/// let file_view = FileView::try_from_bytes_with(&contents, options)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FileViewOptions {
+    require_final_odb: bool,
+    require_run_number_match: bool,
+    max_event_size: u32,
+    max_bank_data_size: u32,
+}
+
+impl FileViewOptions {
+    /// Creates the default options.
+    pub fn new() -> Self {
+        FileViewOptions {
+            require_final_odb: true,
+            require_run_number_match: true,
+            max_event_size: u32::MAX,
+            max_bank_data_size: u32::MAX,
+        }
+    }
+    /// Sets whether the file must end with a final ODB dump (default `true`).
+    ///
+    /// If `false`, a file that ends right after its last event is valid. See
+    /// [`FileView::has_final_odb`].
+    pub fn require_final_odb(mut self, require_final_odb: bool) -> Self {
+        self.require_final_odb = require_final_odb;
+        self
+    }
+    /// Sets whether the run number in the final ODB dump must match the one in
+    /// the initial ODB dump (default `true`).
+    pub fn require_run_number_match(mut self, require_run_number_match: bool) -> Self {
+        self.require_run_number_match = require_run_number_match;
+        self
+    }
+    /// Sets the maximum size in bytes of an event, as declared in its header
+    /// (default no limit).
+    ///
+    /// The declared size does not include the 16-byte event header. Parsing
+    /// stops at the first event with a larger size. Together with
+    /// [`FileViewOptions::max_bank_data_size`], this bounds the work done on
+    /// untrusted input with corrupted sizes.
+    pub fn max_event_size(mut self, max_event_size: u32) -> Self {
+        self.max_event_size = max_event_size;
+        self
+    }
+    /// Sets the maximum size in bytes of the data of a bank, as declared in
+    /// its header (default no limit).
+    ///
+    /// Parsing stops at the first data bank with more data.
+    pub fn max_bank_data_size(mut self, max_bank_data_size: u32) -> Self {
+        self.max_bank_data_size = max_bank_data_size;
+        self
+    }
+}
+
+impl Default for FileViewOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> FileView<'a> {
+    /// Create a native view to the underlying file from its representation as a
+    /// byte slice.
+    pub fn try_from_bytes(bytes: &'a [u8]) -> Result<Self, ParseError> {
+        Self::try_from_bytes_with(bytes, FileViewOptions::default())
+    }
+    /// Create a native view to the underlying file from its representation as a
+    /// byte slice, with the given parsing options.
+    pub fn try_from_bytes_with(
+        bytes: &'a [u8],
+        options: FileViewOptions,
+    ) -> Result<Self, ParseError> {
+        parse::file_view(options).parse(bytes).map_err(|e| {
+            let (event_index, bank_name) = parse::locate_error(bytes, options);
+            ParseError {
+                offset: e.offset(),
+                inner: e.into_inner(),
+                event_index,
+                bank_name,
+            }
+        })
+    }
+    /// Leniently create a native view to the underlying file from its
+    /// representation as a byte slice.
+    ///
+    /// This is meant to recover data from damaged files, e.g. from a run that
+    /// crashed mid-write and left a truncated last event and no final ODB dump.
+    /// As many complete events as possible are parsed; the remaining bytes are
+    /// ignored and described by the returned [`Recovery`]. The recovery is
+    /// [`None`] if the file is not damaged.
+    ///
+    /// The run numbers in the initial and final ODB dumps are not required to
+    /// match; compare [`FileView::run_number`] and
+    /// [`FileView::final_run_number`] to detect a mismatch.
+    ///
+    /// An error is returned only if the initial ODB dump cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let contents = std::fs::read("crashed_run.mid")?;
+    /// let (file_view, recovery) = midasio::FileView::try_from_bytes_lenient(&contents)?;
+    /// if let Some(recovery) = recovery {
+    ///     eprintln!(
+    ///         "ignored `{}` bytes at offset `{}`",
+    ///         recovery.discarded_bytes(),
+    ///         recovery.offset()
+    ///     );
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_from_bytes_lenient(bytes: &'a [u8]) -> Result<(Self, Option<Recovery>), ParseError> {
+        let (file_view, remaining) =
+            (parse::file_view_lenient, rest)
+                .parse(bytes)
+                .map_err(|e| ParseError {
+                    offset: e.offset(),
+                    inner: e.into_inner(),
+                    event_index: None,
+                    bank_name: None,
+                })?;
+        let recovery = (!remaining.is_empty() || !file_view.has_final_odb).then(|| Recovery {
+            offset: bytes.len() - remaining.len(),
+            discarded: remaining.len(),
+            final_odb: file_view.has_final_odb,
+        });
+
+        Ok((file_view, recovery))
+    }
+    /// Returns the run number of the file.
+    pub fn run_number(&self) -> u32 {
+        self.run_number
+    }
+    /// Returns the unix timestamp of the initial ODB dump.
+    pub fn initial_timestamp(&self) -> u32 {
+        self.initial_timestamp
     }
     /// Returns the initial ODB dump.
     pub fn initial_odb(&self) -> &'a [u8] {
         self.initial_odb
     }
+    /// Returns the run number in the header of the final ODB dump.
+    ///
+    /// This is the same as [`FileView::run_number`] (i.e. the run number in
+    /// the initial ODB dump) unless the file was parsed without
+    /// [`FileViewOptions::require_run_number_match`] or with
+    /// [`FileView::try_from_bytes_lenient`]. Returns zero if the file has no
+    /// final ODB dump (see [`FileView::has_final_odb`]).
+    pub fn final_run_number(&self) -> u32 {
+        self.final_run_number
+    }
     /// Returns the unix timestamp of the final ODB dump.
+    ///
+    /// Returns zero if the file has no final ODB dump (see
+    /// [`FileView::has_final_odb`]).
     pub fn final_timestamp(&self) -> u32 {
         self.final_timestamp
     }
     /// Returns the final ODB dump.
+    ///
+    /// Returns an empty slice if the file has no final ODB dump (see
+    /// [`FileView::has_final_odb`]).
     pub fn final_odb(&self) -> &'a [u8] {
         self.final_odb
     }
+    /// Returns the initial ODB dump as a string, or [`None`] if it is not
+    /// valid UTF-8.
+    ///
+    /// Modern MIDAS ODB dumps are text (XML or JSON); see
+    /// [`FileView::initial_odb`] for binary dumps.
+    pub fn initial_odb_str(&self) -> Option<&'a str> {
+        core::str::from_utf8(self.initial_odb).ok()
+    }
+    /// Returns the final ODB dump as a string, or [`None`] if it is not valid
+    /// UTF-8.
+    ///
+    /// Modern MIDAS ODB dumps are text (XML or JSON); see
+    /// [`FileView::final_odb`] for binary dumps.
+    pub fn final_odb_str(&self) -> Option<&'a str> {
+        core::str::from_utf8(self.final_odb).ok()
+    }
+    /// Returns a best guess of the format of the initial ODB dump.
+    ///
+    /// See [`OdbFormat::detect`].
+    pub fn initial_odb_format(&self) -> OdbFormat {
+        OdbFormat::detect(self.initial_odb)
+    }
+    /// Returns a best guess of the format of the final ODB dump.
+    ///
+    /// See [`OdbFormat::detect`].
+    pub fn final_odb_format(&self) -> OdbFormat {
+        OdbFormat::detect(self.final_odb)
+    }
+    /// Returns `true` if the file ends with a final ODB dump.
+    ///
+    /// This is always `true` for a file created with
+    /// [`FileView::try_from_bytes`]. It can only be `false` if the final ODB
+    /// dump is not required by the [`FileViewOptions`], or if the file was
+    /// created with [`FileView::try_from_bytes_lenient`].
+    pub fn has_final_odb(&self) -> bool {
+        self.has_final_odb
+    }
+    /// Returns the total number of bytes the file occupies on disk.
+    ///
+    /// This includes the headers and contents of the ODB dumps, and all the
+    /// events (see [`EventView::size_bytes`]). For a valid file this is
+    /// exactly the length of the input bytes.
+    pub fn size_bytes(&self) -> usize {
+        ODB_HEADER_LENGTH
+            + self.initial_odb.len()
+            + self.iter().map(EventView::size_bytes).sum::<usize>()
+            + if self.has_final_odb {
+                ODB_HEADER_LENGTH + self.final_odb.len()
+            } else {
+                0
+            }
+    }
+    /// Returns the byte order of the file.
+    pub fn endianness(&self) -> Endianness {
+        self.endianness
+    }
     /// Returns an iterator over the events of the file.
-    pub fn iter(&self) -> std::slice::Iter<'_, EventView<'a>> {
+    ///
+    /// All the events are parsed up front, so the iterator is an
+    /// [`ExactSizeIterator`] and a [`DoubleEndedIterator`]. See [`try_events`]
+    /// or [`EventReader`] to parse events one at a time instead.
+    pub fn iter(&self) -> core::slice::Iter<'_, EventView<'a>> {
         self.into_iter()
     }
+    /// Returns the event at the given index, or [`None`] if the index is out of
+    /// bounds.
+    pub fn get(&self, index: usize) -> Option<&EventView<'a>> {
+        self.event_views.get(index)
+    }
+    /// Returns the number of events in the file.
+    pub fn len(&self) -> usize {
+        self.event_views.len()
+    }
+    /// Returns `true` if the file has no events.
+    pub fn is_empty(&self) -> bool {
+        self.event_views.is_empty()
+    }
+    /// Returns an iterator over the events of the file in reverse order, i.e.
+    /// from the last event to the first.
+    ///
+    /// This is the same as `file_view.iter().rev()`. The owning iterator (i.e.
+    /// `file_view.into_iter()`) can also be reversed.
+    pub fn iter_rev(&self) -> core::iter::Rev<core::slice::Iter<'_, EventView<'a>>> {
+        self.iter().rev()
+    }
+    /// Returns an iterator over the events of the file paired with their
+    /// zero-based index.
+    ///
+    /// The index is the position of the event in the file, also when iterating
+    /// from the back.
+    pub fn indexed(&self) -> core::iter::Enumerate<core::slice::Iter<'_, EventView<'a>>> {
+        self.iter().enumerate()
+    }
+    /// Returns an iterator over all the data banks in the file, event after
+    /// event.
+    pub fn iter_banks(&self) -> impl DoubleEndedIterator<Item = &BankView<'a>> + '_ {
+        self.iter().flat_map(EventView::iter)
+    }
+    /// Returns a parallel iterator over all the data banks in the file.
+    ///
+    /// See [`FileView::iter_banks`].
+    #[cfg(feature = "rayon")]
+    pub fn par_iter_banks(&self) -> impl ParallelIterator<Item = &BankView<'a>> + '_ {
+        self.event_views.par_iter().flat_map_iter(EventView::iter)
+    }
+    /// Returns an iterator over the events of the file with the given ID.
+    pub fn events_by_id(&self, id: u16) -> impl DoubleEndedIterator<Item = &EventView<'a>> + '_ {
+        self.iter().filter(move |event_view| event_view.id() == id)
+    }
+    /// Returns an iterator over the events of the file whose trigger mask has
+    /// at least one bit in common with `mask`.
+    ///
+    /// This follows the MIDAS convention for event requests, e.g. a `mask` of
+    /// `0xFFFF` matches any event with a non-zero trigger mask.
+    pub fn events_by_trigger_mask(
+        &self,
+        mask: u16,
+    ) -> impl DoubleEndedIterator<Item = &EventView<'a>> + '_ {
+        self.iter()
+            .filter(move |event_view| event_view.trigger_mask() & mask != 0)
+    }
+    /// Returns an iterator over the events of the file whose data banks have
+    /// the given format.
+    ///
+    /// All the data banks in an event have the same format, but a file can mix
+    /// events with different formats.
+    pub fn events_with_bank_type(
+        &self,
+        bank_type: BankType,
+    ) -> impl DoubleEndedIterator<Item = &EventView<'a>> + '_ {
+        self.iter()
+            .filter(move |event_view| event_view.bank_type() == bank_type)
+    }
+    /// Returns `true` if at least one event in the file has a data bank with
+    /// the given name.
+    ///
+    /// See [`EventView::contains_bank`].
+    pub fn any_event_contains_bank(&self, name: &[u8; 4]) -> bool {
+        self.iter().any(|event_view| event_view.contains_bank(name))
+    }
+    /// Returns an iterator over the runs of consecutive events with the same
+    /// ID, paired with that ID.
+    ///
+    /// Only adjacent events are grouped, so the same ID can appear in multiple
+    /// groups. Each group is never empty.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let contents = std::fs::read("example.mid")?;
+    /// let file_view = midasio::FileView::try_from_bytes(&contents)?;
+    ///
+    /// for (id, event_views) in file_view.group_by_id() {
+    ///     println!("{} consecutive events with ID `{id}`", event_views.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn group_by_id(&self) -> impl DoubleEndedIterator<Item = (u16, &[EventView<'a>])> + '_ {
+        self.event_views
+            .chunk_by(|a, b| a.id() == b.id())
+            .map(|event_views| (event_views[0].id(), event_views))
+    }
+    /// Returns an iterator over the events of the file whose timestamp is in
+    /// the inclusive range `[start, end]`.
+    ///
+    /// This checks every event in the file. See
+    /// [`FileView::events_in_time_range_sorted`] for a faster alternative.
+    pub fn events_in_time_range(
+        &self,
+        start: u32,
+        end: u32,
+    ) -> impl DoubleEndedIterator<Item = &EventView<'a>> + '_ {
+        self.iter()
+            .filter(move |event_view| (start..=end).contains(&event_view.timestamp()))
+    }
+    /// Returns an iterator over the events of the file whose timestamp is in
+    /// the inclusive range `[start, end]`, assuming that the timestamps of the
+    /// events are non-decreasing.
+    ///
+    /// The range is located with a binary search, i.e. in `O(log n)` time. This
+    /// is the case for files written by the MIDAS logger, but it is not
+    /// validated; if the timestamps are not sorted, the returned events are
+    /// unspecified (but always a contiguous run of events from the file).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let contents = std::fs::read("example.mid")?;
+    /// let file_view = midasio::FileView::try_from_bytes(&contents)?;
+    ///
+    /// let start = file_view.initial_timestamp();
+    /// for event_view in file_view.events_in_time_range_sorted(start, start.saturating_add(60)) {
+    ///     // Do something with the events in the first minute of the run.
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn events_in_time_range_sorted(
+        &self,
+        start: u32,
+        end: u32,
+    ) -> core::slice::Iter<'_, EventView<'a>> {
+        let first = self
+            .event_views
+            .partition_point(|event_view| event_view.timestamp() < start);
+        let last = self
+            .event_views
+            .partition_point(|event_view| event_view.timestamp() <= end);
+        self.event_views.get(first..last).unwrap_or_default().iter()
+    }
+    /// Returns the pairs of consecutive serial numbers `(before, after)` in the
+    /// file where the serial number does not increase by exactly one.
+    ///
+    /// Serial numbers wrap around, so `u32::MAX` followed by `0` is not a gap.
+    /// Gaps usually indicate dropped events.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let contents = std::fs::read("example.mid")?;
+    /// let file_view = midasio::FileView::try_from_bytes(&contents)?;
+    ///
+    /// for (before, after) in file_view.find_serial_gaps() {
+    ///     eprintln!("events between `{before}` and `{after}` are missing");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn find_serial_gaps(&self) -> Vec<(u32, u32)> {
+        self.event_views
+            .windows(2)
+            .map(|pair| (pair[0].serial_number(), pair[1].serial_number()))
+            .filter(|&(before, after)| before.wrapping_add(1) != after)
+            .collect()
+    }
+    /// Returns the number of data banks with each name across all the events
+    /// in the file.
+    #[cfg(feature = "std")]
+    pub fn bank_name_counts(&self) -> std::collections::HashMap<[u8; 4], usize> {
+        let mut counts = std::collections::HashMap::new();
+        for bank_view in self.iter_banks() {
+            *counts.entry(bank_view.name()).or_insert(0) += 1;
+        }
+        counts
+    }
+    /// Returns a summary of the contents of the file.
+    ///
+    /// All the data banks are visited in a single pass.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let contents = std::fs::read("example.mid")?;
+    /// let file_view = midasio::FileView::try_from_bytes(&contents)?;
+    ///
+    /// println!("{:#?}", file_view.summary());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn summary(&self) -> FileSummary {
+        let mut summary = FileSummary {
+            run_number: self.run_number,
+            initial_timestamp: self.initial_timestamp,
+            final_timestamp: self.final_timestamp,
+            event_count: self.len(),
+            bank_count: 0,
+            bank_name_counts: std::collections::HashMap::new(),
+            data_types: std::collections::HashSet::new(),
+            data_len: 0,
+        };
+        for bank_view in self.iter_banks() {
+            summary.bank_count += 1;
+            *summary
+                .bank_name_counts
+                .entry(bank_view.name())
+                .or_insert(0) += 1;
+            summary.data_types.insert(bank_view.data_type());
+            summary.data_len += bank_view.data().len();
+        }
+        summary
+    }
+    /// Returns an object that implements [`Display`](core::fmt::Display) to
+    /// print a one-line summary of the header of the file.
+    ///
+    /// Formatting does not allocate. See [`FileView::summary`] for a summary
+    /// of the contents of the file.
+    pub fn display_summary(&self) -> FileSummaryDisplay<'_, 'a> {
+        FileSummaryDisplay(self)
+    }
+    /// Returns the number of data banks with the given name across all the
+    /// events in the file.
+    pub fn bank_name_count(&self, name: [u8; 4]) -> usize {
+        self.iter_banks()
+            .filter(|bank_view| bank_view.name() == name)
+            .count()
+    }
+    /// Writes the data of all the banks with the given name in CSV format.
+    ///
+    /// The output has one row per event that contains the bank, with columns
+    /// `event_serial,timestamp,v0,v1,...` where `v0,v1,...` are the values in
+    /// the bank decoded according to its [`DataType`]. Rows are not padded, so
+    /// banks with fewer values result in rows with fewer columns. A
+    /// [`DataType::Str`], [`DataType::Key`], or [`DataType::Link`] bank is
+    /// written as a single quoted value, and the contents of
+    /// [`DataType::Array`] and [`DataType::Struct`] banks are written byte by
+    /// byte.
+    ///
+    /// If an event has more than one bank with the given name, only the first
+    /// one is written.
+    #[cfg(feature = "std")]
+    pub fn write_bank_csv<W: std::io::Write>(
+        &self,
+        name: [u8; 4],
+        w: &mut W,
+    ) -> std::io::Result<()> {
+        let rows = || {
+            self.iter().filter_map(|event_view| {
+                event_view
+                    .bank(&name)
+                    .map(|bank_view| (event_view, bank_view))
+            })
+        };
+
+        let columns = rows()
+            .map(|(_, bank_view)| match bank_view.data_type() {
+                DataType::Str | DataType::Key | DataType::Link => 1,
+                data_type => bank_view.data().len() / data_type.size().unwrap_or(1),
+            })
+            .max()
+            .unwrap_or(0);
+        write!(w, "event_serial,timestamp")?;
+        for i in 0..columns {
+            write!(w, ",v{i}")?;
+        }
+        writeln!(w)?;
+
+        for (event_view, bank_view) in rows() {
+            write!(
+                w,
+                "{},{}",
+                event_view.serial_number(),
+                event_view.timestamp()
+            )?;
+            write_csv_values(w, bank_view)?;
+            writeln!(w)?;
+        }
+        Ok(())
+    }
+    /// Checks that the events of the file match an expected schema.
+    ///
+    /// The schema is a list of event IDs, each with the name and data type of
+    /// all the data banks that events with that ID must contain. Events with an
+    /// ID that is not part of the schema are not checked.
+    ///
+    /// Returns an error describing the first mismatch found.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use midasio::DataType;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let contents = std::fs::read("example.mid")?;
+    /// let file_view = midasio::FileView::try_from_bytes(&contents)?;
+    ///
+    /// file_view.validate_schema(&[
+    ///     (1, &[(*b"ADC0", DataType::U16), (*b"TDC0", DataType::U32)]),
+    ///     (2, &[(*b"SCLR", DataType::U32)]),
+    /// ])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn validate_schema(
+        &self,
+        expected: &[(u16, &[([u8; 4], DataType)])],
+    ) -> Result<(), SchemaError> {
+        for (event_index, event_view) in self.iter().enumerate() {
+            let Some((_, banks)) = expected.iter().find(|(id, _)| *id == event_view.id()) else {
+                continue;
+            };
+
+            let mismatch = banks
+                .iter()
+                .find_map(|&(name, expected)| match event_view.bank(&name) {
+                    None => Some(SchemaErrorKind::MissingBank { name }),
+                    Some(bank_view) if bank_view.data_type() != expected => {
+                        Some(SchemaErrorKind::WrongDataType {
+                            name,
+                            expected,
+                            found: bank_view.data_type(),
+                        })
+                    }
+                    Some(_) => None,
+                })
+                .or_else(|| {
+                    event_view
+                        .iter()
+                        .find(|bank_view| banks.iter().all(|(name, _)| *name != bank_view.name()))
+                        .map(|bank_view| SchemaErrorKind::ExtraBank {
+                            name: bank_view.name(),
+                        })
+                });
+            if let Some(kind) = mismatch {
+                return Err(SchemaError { event_index, kind });
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for FileView<'a> {
+    type Error = ParseError;
+
+    /// Same as [`FileView::try_from_bytes`].
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_bytes(bytes)
+    }
+}
+
+impl<'a> core::ops::Index<usize> for FileView<'a> {
+    type Output = EventView<'a>;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.event_views[index]
+    }
 }
 
 impl<'a, 'b> IntoIterator for &'b FileView<'a> {
     type Item = &'b EventView<'a>;
-    type IntoIter = std::slice::Iter<'b, EventView<'a>>;
+    type IntoIter = core::slice::Iter<'b, EventView<'a>>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.event_views.iter()
@@ -207,7 +2245,7 @@ impl<'a, 'b> IntoIterator for &'b FileView<'a> {
 
 impl<'a> IntoIterator for FileView<'a> {
     type Item = EventView<'a>;
-    type IntoIter = std::vec::IntoIter<EventView<'a>>;
+    type IntoIter = alloc::vec::IntoIter<EventView<'a>>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.event_views.into_vec().into_iter()
@@ -234,6 +2272,72 @@ impl<'a, 'b> rayon::iter::IntoParallelIterator for &'b FileView<'a> {
     }
 }
 
+#[cfg(feature = "std")]
+fn write_csv_values<W: std::io::Write>(w: &mut W, bank_view: &BankView) -> std::io::Result<()> {
+    macro_rules! write_values {
+        ($values:expr) => {
+            for value in $values {
+                write!(w, ",{value}")?;
+            }
+        };
+    }
+
+    match bank_view.data_type() {
+        DataType::U8 | DataType::Array | DataType::Struct => {
+            write_values!(bank_values!(bank_view, u8))
+        }
+        DataType::I8 => write_values!(bank_values!(bank_view, i8)),
+        DataType::U16 => write_values!(bank_values!(bank_view, u16)),
+        DataType::I16 => write_values!(bank_values!(bank_view, i16)),
+        DataType::U32 => write_values!(bank_values!(bank_view, u32)),
+        DataType::I32 => write_values!(bank_values!(bank_view, i32)),
+        DataType::Bool => write_values!(bank_values!(bank_view, u32).map(|n| n != 0)),
+        DataType::F32 => write_values!(bank_values!(bank_view, f32)),
+        DataType::F64 => write_values!(bank_values!(bank_view, f64)),
+        DataType::Str | DataType::Key | DataType::Link => {
+            let value = bank_view.as_str_lossy().unwrap();
+            write!(w, ",\"{}\"", value.replace('"', "\"\""))?;
+        }
+        DataType::I64 => write_values!(bank_values!(bank_view, i64)),
+        DataType::U64 => write_values!(bank_values!(bank_view, u64)),
+    }
+    Ok(())
+}
+
+/// Returns the byte order of a MIDAS file assuming that the input slice has the
+/// correct MIDAS file format.
+///
+/// Only the begin-of-run ID at the start of the file is inspected. This is
+/// useful for checking the endianness of a file without having to parse its
+/// entire contents. Returns an error if the endianness cannot be determined.
+///
+/// # Examples
+///
+/// ```
+/// use midasio::Endianness;
+///
+/// // Note that the following is an invalid MIDAS file; it only contains the
+/// // begin-of-run ID and an invalid magic marker.
+/// let bytes = b"\x80\x00\xFF\xFF";
+///
+/// let endianness = midasio::file_endianness_unchecked(bytes)?;
+/// assert_eq!(endianness, Endianness::Big);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn file_endianness_unchecked(bytes: &[u8]) -> Result<Endianness, ParseError> {
+    terminated(
+        parse::endianness.context(StrContext::Label("begin-of-run id")),
+        rest,
+    )
+    .parse(bytes)
+    .map_err(|e| ParseError {
+        offset: e.offset(),
+        inner: e.into_inner(),
+        event_index: None,
+        bank_name: None,
+    })
+}
+
 /// Returns the run number assuming that the input slice has the correct MIDAS
 /// file format.
 ///
@@ -255,13 +2359,13 @@ impl<'a, 'b> rayon::iter::IntoParallelIterator for &'b FileView<'a> {
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
 pub fn run_number_unchecked(bytes: &[u8]) -> Result<u32, ParseError> {
-    fn run_number(input: &mut &[u8]) -> PResult<u32> {
+    fn run_number(input: &mut &[u8]) -> ModalResult<u32> {
         let endianness = parse::endianness
             .context(StrContext::Label("begin-of-run id"))
             .parse_next(input)?;
         delimited(
             take(2usize).context(StrContext::Label("magic marker")),
-            u32(endianness).context(StrContext::Label("run number")),
+            parse::u32(endianness).context(StrContext::Label("run number")),
             rest,
         )
         .parse_next(input)
@@ -270,6 +2374,8 @@ pub fn run_number_unchecked(bytes: &[u8]) -> Result<u32, ParseError> {
     run_number.parse(bytes).map_err(|e| ParseError {
         offset: e.offset(),
         inner: e.into_inner(),
+        event_index: None,
+        bank_name: None,
     })
 }
 
@@ -294,13 +2400,13 @@ pub fn run_number_unchecked(bytes: &[u8]) -> Result<u32, ParseError> {
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
 pub fn initial_timestamp_unchecked(bytes: &[u8]) -> Result<u32, ParseError> {
-    fn initial_timestamp(input: &mut &[u8]) -> PResult<u32> {
+    fn initial_timestamp(input: &mut &[u8]) -> ModalResult<u32> {
         let endianness = parse::endianness
             .context(StrContext::Label("begin-of-run id"))
             .parse_next(input)?;
         delimited(
             take(6usize).context(StrContext::Label("magic marker and run number")),
-            u32(endianness).context(StrContext::Label("initial timestamp")),
+            parse::u32(endianness).context(StrContext::Label("initial timestamp")),
             rest,
         )
         .parse_next(input)
@@ -309,17 +2415,358 @@ pub fn initial_timestamp_unchecked(bytes: &[u8]) -> Result<u32, ParseError> {
     initial_timestamp.parse(bytes).map_err(|e| ParseError {
         offset: e.offset(),
         inner: e.into_inner(),
+        event_index: None,
+        bank_name: None,
+    })
+}
+
+/// Returns the total length in bytes of a MIDAS file according to the sizes
+/// in its headers, assuming the correct MIDAS file format.
+///
+/// Only the initial ODB dump header, the event headers, and the final ODB dump
+/// header are read; the contents of the events are not validated. Comparing
+/// the result with the length of the input is a cheap way to detect a
+/// truncated file (or trailing bytes). If the input ends within the initial
+/// ODB dump or an event, the result is the end of that ODB dump or event (a
+/// lower bound of the length of the complete file). Returns an error if the
+/// length cannot be determined, i.e. if the input ends within or right before
+/// a header.
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let contents = std::fs::read("example.mid")?;
+///
+/// let expected_len = midasio::expected_file_len(&contents)?;
+/// if contents.len() < expected_len {
+///     eprintln!("file is missing at least `{}` bytes", expected_len - contents.len());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn expected_file_len(bytes: &[u8]) -> Result<usize, ParseError> {
+    // Only overflows on 16-bit targets for bogus sizes.
+    fn size(size: u32) -> usize {
+        usize::try_from(size).unwrap_or(usize::MAX)
+    }
+
+    let (endianness, _, _, initial_odb_size) =
+        parse::parse_at(terminated(parse::initial_header, rest), bytes, 0)?;
+    let mut offset = ODB_HEADER_LENGTH.saturating_add(size(initial_odb_size));
+    // Advance by the declared sizes alone, so that a truncated ODB dump or
+    // event does not prevent computing the expected length.
+    while let Some(input) = bytes.get(offset..) {
+        let is_final = parse::is_final_header(endianness, input);
+        let label = if is_final {
+            "final odb dump"
+        } else {
+            "event size"
+        };
+        // Both an event header (up to the event size) and the final ODB dump
+        // header are 16 bytes long, and end with the size of what follows.
+        let size_field =
+            preceded(take(12usize), parse::u32(endianness)).context(StrContext::Label(label));
+        let declared_size = parse::parse_at(terminated(size_field, rest), input, offset)?;
+        offset = offset
+            .saturating_add(ODB_HEADER_LENGTH)
+            .saturating_add(size(declared_size));
+        if is_final {
+            return Ok(offset);
+        }
+    }
+    Ok(offset)
+}
+
+/// Returns an iterator that parses the events of a MIDAS file one at a time.
+///
+/// Unlike [`FileView::try_from_bytes`], which fails if any part of the file is
+/// invalid, this yields all the events that precede a corrupted one. The
+/// iterator yields an error (and then ends) for an invalid initial ODB dump,
+/// the first invalid event, or an invalid final ODB dump; otherwise, it ends
+/// after the last event.
+///
+/// The number of events is not known until the end of the file is reached, so
+/// the iterator does not implement [`ExactSizeIterator`]. For the same
+/// reason, it does not implement [`DoubleEndedIterator`]; see
+/// [`FileView::iter_rev`] to iterate in reverse.
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let contents = std::fs::read("corrupted.mid")?;
+///
+/// for event_view in midasio::try_events(&contents) {
+///     match event_view {
+///         Ok(event_view) => {
+///             // Do something with each valid event.
+///         }
+///         Err(e) => eprintln!("corrupted file: {e}"),
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn try_events(bytes: &[u8]) -> impl Iterator<Item = Result<EventView<'_>, ParseError>> + '_ {
+    try_events_with(bytes, |endianness, input, offset| {
+        let (mut event_view, remaining) = parse::parse_at(
+            (parse::event_view(endianness, FileViewOptions::new()), rest),
+            input,
+            offset,
+        )?;
+        event_view.byte_offset = offset;
+        Ok((event_view, remaining))
+    })
+}
+
+/// Parses the events of a MIDAS file one at a time, and calls `f` with each
+/// of them.
+///
+/// This is useful for a single pass over a large file without collecting
+/// all of its events (as [`FileView`] does). Returning
+/// [`ControlFlow::Break`](core::ops::ControlFlow::Break) from `f` stops
+/// parsing early; the rest of the file is then not validated. Otherwise,
+/// this returns an error if the file is not valid (see [`try_events`]), after
+/// `f` has been called with all the events before the invalid one.
+///
+/// # Examples
+///
+/// ```no_run
+/// use core::ops::ControlFlow;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let contents = std::fs::read("example.mid")?;
+///
+/// // Find the first event with a given serial number.
+/// let mut found = None;
+/// midasio::for_each_event(&contents, |event_view| {
+///     if event_view.serial_number() == 1234 {
+///         found = Some(event_view.byte_offset());
+///         ControlFlow::Break(())
+///     } else {
+///         ControlFlow::Continue(())
+///     }
+/// })?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn for_each_event<F>(bytes: &[u8], mut f: F) -> Result<(), ParseError>
+where
+    F: FnMut(&EventView) -> core::ops::ControlFlow<()>,
+{
+    for event_view in try_events(bytes) {
+        if f(&event_view?).is_break() {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// The error type returned by [`transform`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum TransformError {
+    /// The input is not a valid MIDAS file.
+    Parse(ParseError),
+    /// An event returned by the closure has a different byte order than the
+    /// input file.
+    EndiannessMismatch {
+        /// Zero-based index of the input event that was replaced.
+        event_index: usize,
+    },
+}
+
+impl core::fmt::Display for TransformError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            TransformError::Parse(_) => write!(f, "failed to parse midas file"),
+            TransformError::EndiannessMismatch { event_index } => write!(
+                f,
+                "byte order of the replacement for event `{event_index}` does not match the file"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TransformError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TransformError::Parse(e) => Some(e),
+            TransformError::EndiannessMismatch { .. } => None,
+        }
+    }
+}
+
+impl From<ParseError> for TransformError {
+    fn from(e: ParseError) -> Self {
+        TransformError::Parse(e)
+    }
+}
+
+/// Parses a MIDAS file and writes a new one in which every event is dropped
+/// or replaced by the output of `f`.
+///
+/// Events for which `f` returns [`None`] are dropped. Otherwise, the raw bytes
+/// of the returned [`OwnedEvent`] are written in place of the original event;
+/// use [`EventView::to_owned_event`] to keep an event unchanged, or
+/// [`write::EventBuilder::build`] to rewrite it. The initial and final ODB
+/// dumps (together with the run number and timestamps) are copied verbatim
+/// from the input.
+///
+/// Returns an error if the input is not a valid MIDAS file (see
+/// [`FileView::try_from_bytes`]), or if an event returned by `f` does not have
+/// the same byte order as the input file.
+///
+/// # Examples
+///
+/// ```no_run
+/// use midasio::write::EventBuilder;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let contents = std::fs::read("example.mid")?;
+///
+/// // Keep only the events with ID 1, and drop their `TRG0` banks.
+/// let output = midasio::transform(&contents, |event_view| {
+///     if event_view.id() != 1 {
+///         return None;
+///     }
+///     let mut builder = EventBuilder::new(event_view.id())
+///         .trigger_mask(event_view.trigger_mask())
+///         .serial_number(event_view.serial_number())
+///         .timestamp(event_view.timestamp());
+///     for bank_view in event_view.iter().filter(|b| b.name() != *b"TRG0") {
+///         let data = bank_view.data().to_vec();
+///         builder = builder.bank(bank_view.name(), bank_view.data_type(), data);
+///     }
+///     builder.build(event_view.endianness()).ok()
+/// })?;
+/// std::fs::write("filtered.mid", output)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn transform<F>(input: &[u8], mut f: F) -> Result<Vec<u8>, TransformError>
+where
+    F: FnMut(EventView) -> Option<OwnedEvent>,
+{
+    let file_view = FileView::try_from_bytes(input)?;
+    let endianness = file_view.endianness;
+    let head_len = ODB_HEADER_LENGTH + file_view.initial_odb.len();
+    let tail_start = head_len + file_view.iter().map(EventView::size_bytes).sum::<usize>();
+
+    let mut bytes = input[..head_len].to_vec();
+    for (event_index, event_view) in file_view.into_iter().enumerate() {
+        if let Some(event) = f(event_view) {
+            if event.endianness != endianness {
+                return Err(TransformError::EndiannessMismatch { event_index });
+            }
+            bytes.extend_from_slice(&event.bytes);
+        }
+    }
+    bytes.extend_from_slice(&input[tail_start..]);
+    Ok(bytes)
+}
+
+// Shared by `try_events` and `try_lazy_events`. The `event` closure parses a
+// single event at the given offset (from the beginning of the file), and
+// returns it together with the remaining input.
+pub(crate) fn try_events_with<'a, T>(
+    bytes: &'a [u8],
+    mut event: impl FnMut(Endianness, &'a [u8], usize) -> Result<(T, &'a [u8]), ParseError> + 'a,
+) -> impl Iterator<Item = Result<T, ParseError>> + 'a {
+    fn initial<'a>(input: &mut &'a [u8]) -> ModalResult<(Endianness, u32, &'a [u8])> {
+        let (endianness, run_number, _, odb_size) = parse::initial_header.parse_next(input)?;
+        take(odb_size)
+            .context(StrContext::Label("initial odb dump"))
+            .parse_next(input)?;
+        Ok((endianness, run_number, rest.parse_next(input)?))
+    }
+
+    let mut state = Some(parse::parse_at(initial, bytes, 0));
+    let mut event_index = 0;
+    core::iter::from_fn(move || {
+        let (endianness, run_number, input) = match state.take()? {
+            Ok(state) => state,
+            Err(e) => return Some(Err(e)),
+        };
+        let offset = bytes.len() - input.len();
+
+        if input.is_empty() || parse::is_final_header(endianness, input) {
+            let end = parse::final_header(endianness, run_number).flat_map(|(_, odb_size)| {
+                take(odb_size).context(StrContext::Label("final odb dump"))
+            });
+            return parse::parse_at(end, input, offset).err().map(Err);
+        }
+        match event(endianness, input, offset) {
+            Ok((event, remaining)) => {
+                event_index += 1;
+                state = Some(Ok((endianness, run_number, remaining)));
+                Some(Ok(event))
+            }
+            Err(e) => Some(Err(ParseError {
+                event_index: Some(event_index),
+                bank_name: parse::invalid_bank_name(endianness, input, u32::MAX),
+                ..e
+            })),
+        }
     })
 }
 
+/// Returns a hexadecimal and ASCII dump of (at most) `len` bytes starting at
+/// offset `start` of the input slice.
+///
+/// Each line of the output has 16 bytes in the classic `offset | hex | ASCII`
+/// layout, with offsets relative to the beginning of the input slice. This is
+/// mainly useful to inspect the bytes around the [`ParseError::offset`] of a
+/// file that failed to parse.
+///
+/// # Examples
+///
+/// ```
+/// let bytes = b"\x00\x80MIDAS file";
+///
+/// let dump = midasio::hexdump(bytes, 2, 5);
+/// assert_eq!(
+///     dump,
+///     "00000002  4d 49 44 41 53                                    |MIDAS|\n"
+/// );
+/// ```
+pub fn hexdump(bytes: &[u8], start: usize, len: usize) -> String {
+    use core::fmt::Write;
+
+    let start = start.min(bytes.len());
+    let end = start.saturating_add(len).min(bytes.len());
+
+    let mut output = String::new();
+    for (i, line) in bytes[start..end].chunks(16).enumerate() {
+        let mut hex = String::new();
+        for (j, b) in line.iter().enumerate() {
+            if j == 8 {
+                hex.push(' ');
+            }
+            write!(hex, "{b:02x} ").unwrap();
+        }
+        let ascii = line
+            .iter()
+            .map(|&b| {
+                if b.is_ascii_graphic() || b == b' ' {
+                    char::from(b)
+                } else {
+                    '.'
+                }
+            })
+            .collect::<String>();
+        writeln!(output, "{:08x}  {hex:<49} |{ascii}|", start + 16 * i).unwrap();
+    }
+    output
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::iter::repeat;
-
-    const BOR_ID: u16 = 0x8000;
-    const EOR_ID: u16 = 0x8001;
-    const MAGIC: u16 = 0x494D;
+    use crate::constants::{BOR_ID, MAGIC};
+    use std::iter::repeat_n;
 
     const INT_DATA_TYPES: [(u16, DataType); 18] = [
         (1, DataType::U8),
@@ -336,8 +2783,8 @@ mod tests {
         (12, DataType::Str),
         (13, DataType::Array),
         (14, DataType::Struct),
-        (15, DataType::Str),
-        (16, DataType::Str),
+        (15, DataType::Key),
+        (16, DataType::Link),
         (17, DataType::I64),
         (18, DataType::U64),
     ];
@@ -490,20 +2937,17 @@ mod tests {
     fn file_view_try_from_le_bytes() {
         let mut events = Vec::new();
 
-        let banks = repeat(bank_16_le([65; 4], 1, &[2; 100]))
-            .take(10)
+        let banks = repeat_n(bank_16_le([65; 4], 1, &[2; 100]), 10)
             .flatten()
             .collect::<Vec<_>>();
         events.extend(event_le(3, 4, 5, 6, 1, &banks));
 
-        let banks = repeat(bank_32_le([65; 4], 1, &[2; 100]))
-            .take(10)
+        let banks = repeat_n(bank_32_le([65; 4], 1, &[2; 100]), 10)
             .flatten()
             .collect::<Vec<_>>();
         events.extend(event_le(3, 4, 5, 6, 17, &banks));
 
-        let banks = repeat(bank_32a_le([65; 4], 1, &[2; 100]))
-            .take(10)
+        let banks = repeat_n(bank_32a_le([65; 4], 1, &[2; 100]), 10)
             .flatten()
             .collect::<Vec<_>>();
         events.extend(event_le(3, 4, 5, 6, 49, &banks));
@@ -539,20 +2983,17 @@ mod tests {
     fn file_view_try_from_be_bytes() {
         let mut events = Vec::new();
 
-        let banks = repeat(bank_16_be([65; 4], 1, &[2; 100]))
-            .take(10)
+        let banks = repeat_n(bank_16_be([65; 4], 1, &[2; 100]), 10)
             .flatten()
             .collect::<Vec<_>>();
         events.extend(event_be(3, 4, 5, 6, 1, &banks));
 
-        let banks = repeat(bank_32_be([65; 4], 1, &[2; 100]))
-            .take(10)
+        let banks = repeat_n(bank_32_be([65; 4], 1, &[2; 100]), 10)
             .flatten()
             .collect::<Vec<_>>();
         events.extend(event_be(3, 4, 5, 6, 17, &banks));
 
-        let banks = repeat(bank_32a_be([65; 4], 1, &[2; 100]))
-            .take(10)
+        let banks = repeat_n(bank_32a_be([65; 4], 1, &[2; 100]), 10)
             .flatten()
             .collect::<Vec<_>>();
         events.extend(event_be(3, 4, 5, 6, 49, &banks));
@@ -1542,4 +3983,3283 @@ mod tests {
         let bytes = b"\x80\x00\xFF\xFF\xFF\xFF\xFF\xFF\x12\x34\x56";
         assert!(initial_timestamp_unchecked(bytes).is_err());
     }
+
+    #[test]
+    fn bank_view_as_str_lossy() {
+        let bank = bank_16_le([65; 4], 12, b"ab\xFFc\0\0");
+        let events = event_le(0, 0, 0, 0, 1, &bank);
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        let bank_view = file_view.iter().next().unwrap().iter().next().unwrap();
+        assert_eq!(bank_view.as_str_lossy().unwrap(), "ab\u{FFFD}c");
+    }
+
+    #[test]
+    fn bank_view_as_str_lossy_non_str() {
+        let bank = bank_16_le([65; 4], 1, b"abc");
+        let events = event_le(0, 0, 0, 0, 1, &bank);
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        let bank_view = file_view.iter().next().unwrap().iter().next().unwrap();
+        assert!(bank_view.as_str_lossy().is_none());
+    }
+
+    #[test]
+    fn file_view_iter_rev() {
+        let mut events = Vec::new();
+        for serial_number in 1..=3 {
+            events.extend(event_le(0, 0, serial_number, 0, 1, &[]));
+        }
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        assert_eq!(
+            file_view.iter_rev().next().unwrap().serial_number(),
+            file_view.iter().last().unwrap().serial_number()
+        );
+        let serial_numbers = file_view
+            .iter_rev()
+            .map(|event| event.serial_number())
+            .collect::<Vec<_>>();
+        assert_eq!(serial_numbers, [3, 2, 1]);
+    }
+
+    #[test]
+    fn data_type_signedness() {
+        assert_eq!(DataType::U8.signedness(), Some(Signedness::Unsigned));
+        assert_eq!(DataType::I8.signedness(), Some(Signedness::Signed));
+        assert_eq!(DataType::U16.signedness(), Some(Signedness::Unsigned));
+        assert_eq!(DataType::I16.signedness(), Some(Signedness::Signed));
+        assert_eq!(DataType::U32.signedness(), Some(Signedness::Unsigned));
+        assert_eq!(DataType::I32.signedness(), Some(Signedness::Signed));
+        assert_eq!(DataType::Bool.signedness(), None);
+        assert_eq!(DataType::F32.signedness(), None);
+        assert_eq!(DataType::F64.signedness(), None);
+        assert_eq!(DataType::Str.signedness(), None);
+        assert_eq!(DataType::Array.signedness(), None);
+        assert_eq!(DataType::Struct.signedness(), None);
+        assert_eq!(DataType::I64.signedness(), Some(Signedness::Signed));
+        assert_eq!(DataType::U64.signedness(), Some(Signedness::Unsigned));
+    }
+
+    #[test]
+    fn bank_view_required_padding() {
+        for len in [0, 1, 7, 8, 9, 100] {
+            for (bank, flags) in [
+                (bank_16_le([65; 4], 1, &vec![0; len]), 1),
+                (bank_32_le([65; 4], 1, &vec![0; len]), 17),
+                (bank_32a_le([65; 4], 1, &vec![0; len]), 49),
+            ] {
+                let events = event_le(0, 0, 0, 0, flags, &bank);
+                let file = file_le(0, 0, b"", &events, 0, b"");
+                let file_view = FileView::try_from_bytes(&file).unwrap();
+
+                let bank_view = file_view.iter().next().unwrap().iter().next().unwrap();
+                assert_eq!(bank_view.required_padding(), (8 - len % 8) % 8);
+                assert_eq!(bank_view.total_on_disk_size(), bank.len());
+            }
+        }
+    }
+
+    #[test]
+    fn event_view_to_owned_event() {
+        let mut banks = bank_16_be(*b"BANK", 4, &[1, 2, 3, 4]);
+        banks.extend(bank_16_be(*b"STR0", 12, b"hello"));
+        let event = event_be(1, 2, 3, 4, 1, &banks);
+        let file = file_be(0, 0, b"", &event, 0, b"");
+
+        let owned = {
+            let file_view = FileView::try_from_bytes(&file).unwrap();
+            // `ToOwned::to_owned` still clones the view itself.
+            let cloned: EventView = file_view[0].to_owned();
+            assert_eq!(cloned.id(), 1);
+            file_view.iter().next().unwrap().to_owned_event()
+        };
+        drop(file);
+
+        assert_eq!(owned.id(), 1);
+        assert_eq!(owned.trigger_mask(), 2);
+        assert_eq!(owned.serial_number(), 3);
+        assert_eq!(owned.timestamp(), 4);
+        assert_eq!(owned.bytes(), &event[..]);
+
+        let event_view = owned.view();
+        assert_eq!(event_view.id(), 1);
+        assert_eq!(event_view.trigger_mask(), 2);
+        assert_eq!(event_view.serial_number(), 3);
+        assert_eq!(event_view.timestamp(), 4);
+        let [bank_1, bank_2] = event_view.iter().collect::<Vec<_>>()[..] else {
+            panic!()
+        };
+        assert_eq!(bank_1.name(), *b"BANK");
+        assert_eq!(bank_1.data_type(), DataType::U16);
+        assert_eq!(bank_1.data(), &[1, 2, 3, 4]);
+        assert_eq!(bank_2.name(), *b"STR0");
+        assert_eq!(bank_2.data_type(), DataType::Str);
+        assert_eq!(bank_2.data(), b"hello");
+    }
+
+    #[test]
+    fn hexdump_multiple_lines() {
+        let bytes = (0x30..0x58).collect::<Vec<u8>>();
+        let expected = "\
+00000004  34 35 36 37 38 39 3a 3b  3c 3d 3e 3f 40 41 42 43  |456789:;<=>?@ABC|
+00000014  44 45 46 47 48 49 4a 4b  4c 4d 4e 4f 50 51 52 53  |DEFGHIJKLMNOPQRS|
+00000024  54 55 56 57                                       |TUVW|
+";
+        assert_eq!(hexdump(&bytes, 4, 100), expected);
+    }
+
+    #[test]
+    fn hexdump_non_printable() {
+        let bytes = b"\x00A\xFF\n";
+        let expected = "00000000  00 41 ff 0a                                       |.A..|\n";
+        assert_eq!(hexdump(bytes, 0, 4), expected);
+    }
+
+    #[test]
+    fn parse_error_hexdump_context() {
+        let mut bytes = file_le(1, 2, b"initial", &[], 3, b"final");
+        let len = bytes.len();
+        bytes.push(0xFF);
+
+        let error = FileView::try_from_bytes(&bytes).unwrap_err();
+        assert_eq!(error.offset(), len);
+        let expected = "\
+00000022  00 05 00 00 00 66 69 6e  61 6c ff                 |.....final.|
+";
+        assert_eq!(error.hexdump_context(&bytes, 20), expected);
+        // The context is clamped to the beginning and end of the input.
+        assert_eq!(
+            error.hexdump_context(&bytes, usize::MAX),
+            hexdump(&bytes, 0, usize::MAX)
+        );
+    }
+
+    #[test]
+    fn hexdump_out_of_bounds() {
+        assert_eq!(hexdump(b"abc", 10, 10), "");
+        assert_eq!(
+            hexdump(b"abc", 1, usize::MAX),
+            "00000001  62 63                                             |bc|\n"
+        );
+    }
+
+    #[test]
+    fn parse_error_offset() {
+        let mut file = file_le(0, 0, b"", &[], 0, b"");
+        file[2..4].copy_from_slice(&[0, 0]);
+        let error = FileView::try_from_bytes(&file).unwrap_err();
+        assert_eq!(error.offset(), 2);
+    }
+
+    #[test]
+    fn event_view_bank_slices() {
+        let mut banks = bank_16_le(*b"FRAG", 1, &[1, 2, 3]);
+        banks.extend(bank_16_le(*b"OTHR", 1, &[4, 5]));
+        banks.extend(bank_16_le(*b"FRAG", 1, &[6]));
+        let events = event_le(0, 0, 0, 0, 1, &banks);
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        let event_view = file_view.iter().next().unwrap();
+        assert_eq!(event_view.bank_slices(*b"FRAG"), [&[1, 2, 3][..], &[6]]);
+        assert_eq!(event_view.bank_slices(*b"OTHR"), [&[4, 5][..]]);
+        assert!(event_view.bank_slices(*b"NONE").is_empty());
+    }
+
+    fn schema_test_file() -> Vec<u8> {
+        let mut events = Vec::new();
+        let mut banks = bank_16_le(*b"ADC0", 4, &[0; 4]);
+        banks.extend(bank_16_le(*b"TDC0", 6, &[0; 8]));
+        events.extend(event_le(1, 0, 0, 0, 1, &banks));
+        events.extend(event_le(2, 0, 0, 0, 1, &bank_16_le(*b"SCLR", 6, &[0; 4])));
+        events.extend(event_le(3, 0, 0, 0, 1, &[]));
+        file_le(0, 0, b"", &events, 0, b"")
+    }
+
+    #[test]
+    fn file_view_validate_schema() {
+        let file = schema_test_file();
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        let result = file_view.validate_schema(&[
+            (1, &[(*b"TDC0", DataType::U32), (*b"ADC0", DataType::U16)]),
+            (2, &[(*b"SCLR", DataType::U32)]),
+        ]);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn file_view_validate_schema_missing_bank() {
+        let file = schema_test_file();
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        let error = file_view
+            .validate_schema(&[(2, &[(*b"SCLR", DataType::U32), (*b"TRIG", DataType::U8)])])
+            .unwrap_err();
+        assert_eq!(error.event_index(), 1);
+        assert_eq!(
+            error.kind(),
+            SchemaErrorKind::MissingBank { name: *b"TRIG" }
+        );
+    }
+
+    #[test]
+    fn file_view_validate_schema_extra_bank() {
+        let file = schema_test_file();
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        let error = file_view
+            .validate_schema(&[(1, &[(*b"ADC0", DataType::U16)])])
+            .unwrap_err();
+        assert_eq!(error.event_index(), 0);
+        assert_eq!(error.kind(), SchemaErrorKind::ExtraBank { name: *b"TDC0" });
+    }
+
+    #[test]
+    fn file_view_validate_schema_wrong_data_type() {
+        let file = schema_test_file();
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        let error = file_view
+            .validate_schema(&[(2, &[(*b"SCLR", DataType::F32)])])
+            .unwrap_err();
+        assert_eq!(error.event_index(), 1);
+        assert_eq!(
+            error.kind(),
+            SchemaErrorKind::WrongDataType {
+                name: *b"SCLR",
+                expected: DataType::F32,
+                found: DataType::U32,
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "event `1` does not match the schema (bank `SCLR` has data type `U32` instead of `F32`)"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn file_view_write_bank_csv() {
+        let mut events = Vec::new();
+        let mut banks = bank_16_le(*b"ADC0", 4, &[1, 0, 2, 0, 0xFF, 0xFF]);
+        banks.extend(bank_16_le(*b"OTHR", 4, &[9, 0]));
+        events.extend(event_le(0, 0, 10, 100, 1, &banks));
+        events.extend(event_le(
+            0,
+            0,
+            11,
+            101,
+            1,
+            &bank_16_le(*b"OTHR", 4, &[9, 0]),
+        ));
+        events.extend(event_le(
+            0,
+            0,
+            12,
+            102,
+            1,
+            &bank_16_le(*b"ADC0", 4, &[3, 1]),
+        ));
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        let mut csv = Vec::new();
+        file_view.write_bank_csv(*b"ADC0", &mut csv).unwrap();
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "event_serial,timestamp,v0,v1,v2\n10,100,1,2,65535\n12,102,259\n"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn file_view_write_bank_csv_be() {
+        let mut events = Vec::new();
+        events.extend(event_be(
+            0,
+            0,
+            1,
+            2,
+            1,
+            &bank_16_be(*b"ADC0", 5, &[0xFF, 0xFE]),
+        ));
+        events.extend(event_be(
+            0,
+            0,
+            3,
+            4,
+            1,
+            &bank_16_be(*b"ADC0", 12, b"a\"b\0"),
+        ));
+        let file = file_be(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        let mut csv = Vec::new();
+        file_view.write_bank_csv(*b"ADC0", &mut csv).unwrap();
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "event_serial,timestamp,v0\n1,2,-2\n3,4,\"a\"\"b\"\n"
+        );
+    }
+
+    #[test]
+    fn bank_view_decode_any() {
+        let mut banks = bank_32_be(*b"BANK", 6, &[0, 0, 0, 1, 0xFF, 0xFF, 0xFF, 0xFF]);
+        banks.extend(bank_32_be(*b"BOOL", 8, &[0, 0, 0, 0, 0, 0, 1, 0]));
+        banks.extend(bank_32_be(*b"STR0", 12, b"abc\0"));
+        let events = event_be(0, 0, 0, 0, 17, &banks);
+        let file = file_be(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        let [u32_bank, bool_bank, str_bank] =
+            file_view.iter().next().unwrap().iter().collect::<Vec<_>>()[..]
+        else {
+            panic!()
+        };
+        let values = u32_bank.decode_any();
+        assert_eq!(values.downcast_ref::<Vec<u32>>().unwrap(), &[1, u32::MAX]);
+        assert!(values.downcast_ref::<Vec<i32>>().is_none());
+        let values = bool_bank.decode_any();
+        assert_eq!(values.downcast_ref::<Vec<bool>>().unwrap(), &[false, true]);
+        let values = str_bank.decode_any();
+        assert_eq!(values.downcast_ref::<String>().unwrap(), "abc");
+    }
+
+    #[test]
+    fn views_are_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<FileView>();
+        assert_send_sync::<EventView>();
+        assert_send_sync::<BankView>();
+        assert_send_sync::<OwnedEvent>();
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn file_view_par_iter_matches_serial() {
+        use rayon::prelude::*;
+
+        let mut events = Vec::new();
+        for serial_number in 0..100 {
+            events.extend(event_le(0, 0, serial_number, 0, 1, &[]));
+        }
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        let serial: u32 = file_view.iter().map(|event| event.serial_number()).sum();
+        let parallel: u32 = file_view
+            .par_iter()
+            .map(|event| event.serial_number())
+            .sum();
+        assert_eq!(parallel, serial);
+    }
+
+    #[test]
+    fn event_view_metadata() {
+        let mut banks = bank_32a_le(*b"BNK0", 1, &[1, 2, 3]);
+        banks.extend(bank_32a_le(*b"BNK1", 1, &[4; 20]));
+        let events = event_le(1, 2, 3, 4, 49, &banks);
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        let event_view = file_view.iter().next().unwrap();
+        let metadata = event_view.metadata();
+        assert_eq!(metadata.id, event_view.id());
+        assert_eq!(metadata.trigger_mask, event_view.trigger_mask());
+        assert_eq!(metadata.serial_number, event_view.serial_number());
+        assert_eq!(metadata.timestamp, event_view.timestamp());
+        assert_eq!(
+            metadata,
+            EventMetadata {
+                id: 1,
+                trigger_mask: 2,
+                serial_number: 3,
+                timestamp: 4,
+                flags: 49,
+                bank_count: 2,
+                payload_len: banks.len(),
+            }
+        );
+    }
+
+    #[test]
+    fn bank_view_try_iter_u32() {
+        let bank = bank_16_le([65; 4], 1, &[1, 0, 0, 0, 2, 0, 0, 0, 3]);
+        let events = event_le(0, 0, 0, 0, 1, &bank);
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        let bank_view = file_view.iter().next().unwrap().iter().next().unwrap();
+        let values = bank_view.try_iter_u32().collect::<Vec<_>>();
+        assert_eq!(values.len(), 3);
+        assert_eq!(values[0], Ok(1));
+        assert_eq!(values[1], Ok(2));
+        assert_eq!(values[2].unwrap_err().offset(), 8);
+    }
+
+    #[test]
+    fn bank_view_try_iter_u32_be() {
+        let bank = bank_16_be([65; 4], 6, &[0, 0, 0, 1, 0, 0, 0, 2]);
+        let events = event_be(0, 0, 0, 0, 1, &bank);
+        let file = file_be(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        let bank_view = file_view.iter().next().unwrap().iter().next().unwrap();
+        let values = bank_view.try_iter_u32().collect::<Vec<_>>();
+        assert_eq!(values, [Ok(1), Ok(2)]);
+    }
+
+    #[test]
+    fn file_view_diff_identical() {
+        let events = event_le(1, 2, 3, 4, 1, &bank_16_le([65; 4], 1, &[1, 2, 3]));
+        let file = file_le(1, 2, b"initial", &events, 3, b"final");
+        let a = FileView::try_from_bytes(&file).unwrap();
+        let b = FileView::try_from_bytes(&file).unwrap();
+
+        let diff = a.diff(&b);
+        assert!(diff.is_empty());
+        assert_eq!(diff.to_string(), "files are identical");
+    }
+
+    #[test]
+    fn file_view_diff_modified() {
+        let mut events = Vec::new();
+        for serial_number in 0..20 {
+            events.extend(event_le(
+                1,
+                0,
+                serial_number,
+                0,
+                1,
+                &bank_16_le([65; 4], 1, &[0]),
+            ));
+        }
+        let file_a = file_le(1, 2, b"initial", &events, 3, b"final");
+
+        let mut events = Vec::new();
+        for serial_number in 0..20 {
+            let data = if serial_number == 1 { [1] } else { [0] };
+            events.extend(event_le(
+                1,
+                0,
+                serial_number,
+                0,
+                1,
+                &bank_16_le([65; 4], 1, &data),
+            ));
+        }
+        events.extend(event_le(1, 0, 20, 0, 1, &[]));
+        let file_b = file_le(1, 2, b"initial", &events, 4, b"final");
+
+        let a = FileView::try_from_bytes(&file_a).unwrap();
+        let b = FileView::try_from_bytes(&file_b).unwrap();
+        let diff = a.diff(&b);
+        assert!(!diff.is_empty());
+        assert_eq!(diff.run_number, None);
+        assert_eq!(diff.event_count, Some((20, 21)));
+        assert_eq!(diff.final_timestamp, Some((3, 4)));
+        assert_eq!(diff.total_event_diffs, 1);
+        let [ref event_diff] = diff.events[..] else {
+            panic!()
+        };
+        assert_eq!(event_diff.index, 1);
+        assert_eq!(event_diff.banks, [0]);
+        assert_eq!(
+            diff.to_string(),
+            "event count: `20` != `21`\nevent `1`: banks [0] differ;\nfinal timestamp: `3` != `4`\n"
+        );
+    }
+
+    #[test]
+    fn file_view_diff_bounded() {
+        let mut events_a = Vec::new();
+        let mut events_b = Vec::new();
+        for serial_number in 0..(FileDiff::MAX_EVENT_DIFFS as u32 + 5) {
+            events_a.extend(event_le(1, 0, serial_number, 0, 1, &[]));
+            events_b.extend(event_le(2, 0, serial_number, 0, 1, &[]));
+        }
+        let file_a = file_le(1, 2, b"", &events_a, 3, b"");
+        let file_b = file_le(1, 2, b"", &events_b, 3, b"");
+
+        let a = FileView::try_from_bytes(&file_a).unwrap();
+        let b = FileView::try_from_bytes(&file_b).unwrap();
+        let diff = a.diff(&b);
+        assert_eq!(diff.events.len(), FileDiff::MAX_EVENT_DIFFS);
+        assert_eq!(diff.total_event_diffs, FileDiff::MAX_EVENT_DIFFS + 5);
+        assert!(diff
+            .to_string()
+            .ends_with("... and `5` more events differ\n"));
+    }
+
+    #[test]
+    fn endianness_native() {
+        assert!(Endianness::native().matches_host());
+        if cfg!(target_endian = "little") {
+            assert!(Endianness::Little.matches_host());
+            assert!(!Endianness::Big.matches_host());
+        } else {
+            assert!(!Endianness::Little.matches_host());
+            assert!(Endianness::Big.matches_host());
+        }
+    }
+
+    #[test]
+    fn file_view_events_by_id() {
+        let mut events = Vec::new();
+        for (id, serial_number) in [(1, 0), (2, 1), (1, 2), (3, 3), (1, 4)] {
+            events.extend(event_le(id, 0, serial_number, 0, 1, &[]));
+        }
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        let serial_numbers = file_view
+            .events_by_id(1)
+            .map(|event| event.serial_number())
+            .collect::<Vec<_>>();
+        assert_eq!(serial_numbers, [0, 2, 4]);
+        let last = file_view.events_by_id(1).next_back().unwrap();
+        assert_eq!(last.serial_number(), 4);
+        assert_eq!(file_view.events_by_id(4).count(), 0);
+    }
+
+    #[test]
+    fn file_view_events_by_trigger_mask() {
+        let mut events = Vec::new();
+        for (trigger_mask, serial_number) in [(0b01, 0), (0b10, 1), (0b11, 2), (0b00, 3)] {
+            events.extend(event_le(0, trigger_mask, serial_number, 0, 1, &[]));
+        }
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        let serial_numbers = |mask| {
+            file_view
+                .events_by_trigger_mask(mask)
+                .map(|event| event.serial_number())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(serial_numbers(0b01), [0, 2]);
+        assert_eq!(serial_numbers(0b10), [1, 2]);
+        assert_eq!(serial_numbers(0xFFFF), [0, 1, 2]);
+        assert!(serial_numbers(0).is_empty());
+    }
+
+    #[test]
+    fn event_view_bank() {
+        let mut banks = bank_16_le(*b"ADC0", 1, &[1]);
+        banks.extend(bank_16_le([0xFF, 0, 1, 2], 1, &[2]));
+        banks.extend(bank_16_le(*b"ADC0", 1, &[3]));
+        let events = event_le(0, 0, 0, 0, 1, &banks);
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        let event_view = file_view.iter().next().unwrap();
+        assert_eq!(event_view.bank(b"ADC0").unwrap().data(), &[1]);
+        assert_eq!(event_view.bank(&[0xFF, 0, 1, 2]).unwrap().data(), &[2]);
+        assert!(event_view.bank(b"NONE").is_none());
+    }
+
+    #[test]
+    fn event_view_banks_named() {
+        let mut banks = bank_16_le(*b"ADC0", 1, &[1]);
+        banks.extend(bank_16_le(*b"ADC1", 1, &[2]));
+        banks.extend(bank_16_le(*b"ADC0", 1, &[3]));
+        let events = event_le(0, 0, 0, 0, 1, &banks);
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        let event_view = file_view.iter().next().unwrap();
+        let data = event_view
+            .banks_named(b"ADC0")
+            .map(|bank_view| bank_view.data())
+            .collect::<Vec<_>>();
+        assert_eq!(data, [&[1], &[3]]);
+        assert_eq!(event_view.banks_named(b"ADC1").count(), 1);
+        assert_eq!(event_view.banks_named(b"NONE").count(), 0);
+    }
+
+    #[test]
+    fn bank_view_values() {
+        let mut banks = bank_16_le(*b"U16_", 4, &[1, 0, 0xFF, 0xFF]);
+        banks.extend(bank_16_le(*b"F64_", 10, &1.5f64.to_le_bytes()));
+        banks.extend(bank_16_le(*b"I32_", 7, &(-2i32).to_le_bytes()));
+        let events = event_le(0, 0, 0, 0, 1, &banks);
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+        let event_view = file_view.iter().next().unwrap();
+
+        let bank_view = event_view.bank(b"U16_").unwrap();
+        let values = bank_view.values_u16(Endianness::Little).unwrap();
+        assert_eq!(values.collect::<Vec<_>>(), [1, u16::MAX]);
+        let values = bank_view.values_u16(Endianness::Big).unwrap();
+        assert_eq!(values.collect::<Vec<_>>(), [256, u16::MAX]);
+        assert!(bank_view.values_i16(Endianness::Little).is_none());
+        assert!(bank_view.values_u32(Endianness::Little).is_none());
+
+        let bank_view = event_view.bank(b"F64_").unwrap();
+        let values = bank_view.values_f64(Endianness::Little).unwrap();
+        assert_eq!(values.collect::<Vec<_>>(), [1.5]);
+
+        let bank_view = event_view.bank(b"I32_").unwrap();
+        let values = bank_view.values_i32(Endianness::Little).unwrap();
+        assert_eq!(values.collect::<Vec<_>>(), [-2]);
+    }
+
+    #[test]
+    fn bank_view_values_be() {
+        let bank = bank_32_be(
+            *b"U64_",
+            18,
+            &[0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0],
+        );
+        let events = event_be(0, 0, 0, 0, 17, &bank);
+        let file = file_be(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        let bank_view = file_view.iter().next().unwrap().bank(b"U64_").unwrap();
+        let values = bank_view.values_u64(Endianness::Big).unwrap();
+        assert_eq!(values.collect::<Vec<_>>(), [1, 256]);
+        assert!(bank_view.values_i64(Endianness::Big).is_none());
+    }
+
+    #[test]
+    fn file_view_endianness() {
+        let events = event_le(0, 0, 0, 0, 1, &bank_16_le([65; 4], 4, &[1, 0]));
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+        assert_eq!(file_view.endianness(), Endianness::Little);
+        let event_view = file_view.iter().next().unwrap();
+        assert_eq!(event_view.endianness(), Endianness::Little);
+        let bank_view = event_view.iter().next().unwrap();
+        assert_eq!(bank_view.endianness(), Endianness::Little);
+        let values = bank_view.values_u16(bank_view.endianness()).unwrap();
+        assert_eq!(values.collect::<Vec<_>>(), [1]);
+
+        let events = event_be(0, 0, 0, 0, 1, &bank_16_be([65; 4], 4, &[0, 1]));
+        let file = file_be(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+        assert_eq!(file_view.endianness(), Endianness::Big);
+        let event_view = file_view.iter().next().unwrap();
+        assert_eq!(event_view.endianness(), Endianness::Big);
+        let bank_view = event_view.iter().next().unwrap();
+        assert_eq!(bank_view.endianness(), Endianness::Big);
+        let values = bank_view.values_u16(bank_view.endianness()).unwrap();
+        assert_eq!(values.collect::<Vec<_>>(), [1]);
+    }
+
+    #[test]
+    fn write_file_builder_empty() {
+        let expected = file_le(1, 2, b"initial", &[], 3, b"final");
+        let bytes = write::FileBuilder::new(1)
+            .initial_timestamp(2)
+            .initial_odb(b"initial".to_vec())
+            .final_timestamp(3)
+            .final_odb(b"final".to_vec())
+            .build()
+            .unwrap();
+        assert_eq!(bytes, expected);
+    }
+
+    #[test]
+    fn write_file_builder_bank_16_le() {
+        let banks = [
+            bank_16_le(*b"BANK", 1, &[0xFF; 3]),
+            bank_16_le(*b"ABCD", 6, &[0xFF; 8]),
+        ]
+        .concat();
+        let event = event_le(1, 2, 3, 4, 1, &banks);
+        let expected = file_le(5, 6, b"initial", &event, 7, b"final");
+
+        let bytes = write::FileBuilder::new(5)
+            .initial_timestamp(6)
+            .initial_odb(b"initial".to_vec())
+            .event(
+                write::EventBuilder::new(1)
+                    .trigger_mask(2)
+                    .serial_number(3)
+                    .timestamp(4)
+                    .bank(*b"BANK", DataType::U8, vec![0xFF; 3])
+                    .bank(*b"ABCD", DataType::U32, vec![0xFF; 8]),
+            )
+            .final_timestamp(7)
+            .final_odb(b"final".to_vec())
+            .build()
+            .unwrap();
+        assert_eq!(bytes, expected);
+    }
+
+    #[test]
+    fn write_file_builder_bank_16_be() {
+        let banks = bank_16_be(*b"BANK", 4, &[0xFF; 2]);
+        let event = event_be(1, 2, 3, 4, 1, &banks);
+        let expected = file_be(5, 6, &[], &event, 7, &[]);
+
+        let bytes = write::FileBuilder::new(5)
+            .endianness(Endianness::Big)
+            .initial_timestamp(6)
+            .event(
+                write::EventBuilder::new(1)
+                    .trigger_mask(2)
+                    .serial_number(3)
+                    .timestamp(4)
+                    .bank(*b"BANK", DataType::U16, vec![0xFF; 2]),
+            )
+            .final_timestamp(7)
+            .build()
+            .unwrap();
+        assert_eq!(bytes, expected);
+    }
+
+    #[test]
+    fn write_file_builder_bank_32a_large() {
+        let large = vec![0xFF; usize::from(u16::MAX) + 1];
+        let banks = [
+            bank_32a_le(*b"SMAL", 1, &[0xFF; 1]),
+            bank_32a_le(*b"LARG", 1, &large),
+        ]
+        .concat();
+        let event = event_le(1, 0, 0, 0, 49, &banks);
+        let expected = file_le(1, 0, &[], &event, 0, &[]);
+
+        let bytes = write::FileBuilder::new(1)
+            .event(
+                write::EventBuilder::new(1)
+                    .bank(*b"SMAL", DataType::U8, vec![0xFF; 1])
+                    .bank(*b"LARG", DataType::U8, large.clone()),
+            )
+            .build()
+            .unwrap();
+        assert_eq!(bytes, expected);
+    }
+
+    #[test]
+    fn write_file_builder_invalid_bank_data() {
+        let result = write::FileBuilder::new(1)
+            .event(write::EventBuilder::new(1).bank(*b"BANK", DataType::U32, vec![0; 3]))
+            .build();
+        assert_eq!(
+            result,
+            Err(write::WriteError::InvalidBankData {
+                name: *b"BANK",
+                data_type: DataType::U32,
+                len: 3,
+            })
+        );
+    }
+
+    #[test]
+    fn write_file_builder_round_trip() {
+        let banks = [
+            bank_32_le(*b"BANK", 7, &[0xFF; 4]),
+            bank_32_le(*b"STRG", 12, b"hello"),
+        ]
+        .concat();
+        let events = [
+            event_le(1, 2, 3, 4, 17, &banks),
+            event_le(5, 6, 7, 8, 17, &[]),
+        ]
+        .concat();
+        let bytes = file_le(9, 10, b"initial", &events, 11, b"final");
+        let file_view = FileView::try_from_bytes(&bytes).unwrap();
+
+        let written = write::FileBuilder::new(file_view.run_number())
+            .initial_timestamp(file_view.initial_timestamp())
+            .initial_odb(file_view.initial_odb().to_vec())
+            .events(file_view.iter().map(write::EventBuilder::from))
+            .final_timestamp(file_view.final_timestamp())
+            .final_odb(file_view.final_odb().to_vec())
+            .build()
+            .unwrap();
+        let written_view = FileView::try_from_bytes(&written).unwrap();
+        assert!(file_view.diff(&written_view).is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn event_reader_le() {
+        let banks = [
+            bank_16_le(*b"BANK", 1, &[0xFF; 3]),
+            bank_16_le(*b"ABCD", 6, &[0xFF; 8]),
+        ]
+        .concat();
+        let events = [
+            event_le(1, 2, 3, 4, 1, &banks),
+            event_le(5, 6, 7, 8, 1, &[]),
+        ]
+        .concat();
+        let bytes = file_le(9, 10, b"initial", &events, 11, b"final");
+        let file_view = FileView::try_from_bytes(&bytes).unwrap();
+
+        let mut reader = EventReader::new(&bytes[..]).unwrap();
+        assert_eq!(reader.endianness(), Endianness::Little);
+        assert_eq!(reader.run_number(), 9);
+        assert_eq!(reader.initial_timestamp(), 10);
+        assert_eq!(reader.initial_odb(), b"initial");
+        assert_eq!(reader.final_timestamp(), None);
+        assert_eq!(reader.final_odb(), None);
+
+        let owned_events: Vec<_> = reader.by_ref().collect::<Result<_, _>>().unwrap();
+        assert_eq!(owned_events.len(), 2);
+        for (owned_event, event_view) in owned_events.iter().zip(&file_view) {
+            assert_eq!(owned_event.bytes(), event_view.to_owned_event().bytes());
+        }
+        assert_eq!(reader.final_timestamp(), Some(11));
+        assert_eq!(reader.final_odb(), Some(&b"final"[..]));
+        assert!(reader.next().is_none());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn event_reader_be() {
+        let banks = bank_32a_be(*b"BANK", 4, &[0xFF; 2]);
+        let event = event_be(1, 2, 3, 4, 49, &banks);
+        let bytes = file_be(5, 6, &[], &event, 7, &[]);
+
+        let mut reader = EventReader::new(&bytes[..]).unwrap();
+        assert_eq!(reader.endianness(), Endianness::Big);
+        let owned_event = reader.next().unwrap().unwrap();
+        assert_eq!(owned_event.id(), 1);
+        assert_eq!(owned_event.serial_number(), 3);
+        let event_view = owned_event.view();
+        let bank_view = event_view.iter().next().unwrap();
+        assert_eq!(bank_view.name(), *b"BANK");
+        assert_eq!(bank_view.data(), &[0xFF; 2]);
+        assert!(reader.next().is_none());
+        assert_eq!(reader.final_timestamp(), Some(7));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn event_reader_truncated() {
+        let event = event_le(1, 2, 3, 4, 1, &bank_16_le(*b"BANK", 1, &[0xFF; 3]));
+        let bytes = file_le(5, 6, &[], &event, 7, &[]);
+
+        let mut reader = EventReader::new(&bytes[..bytes.len() - 30]).unwrap();
+        let Some(Err(ReadError::Io(e))) = reader.next() else {
+            panic!("expected io error");
+        };
+        assert_eq!(e.kind(), std::io::ErrorKind::UnexpectedEof);
+        assert!(reader.next().is_none());
+        assert_eq!(reader.final_odb(), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn event_reader_parse_error_offset() {
+        let events = [event_le(1, 2, 3, 4, 1, &[]), event_le(1, 2, 3, 4, 2, &[])].concat();
+        let bytes = file_le(5, 6, b"odb", &events, 7, &[]);
+
+        let mut reader = EventReader::new(&bytes[..]).unwrap();
+        assert!(reader.next().unwrap().is_ok());
+        let Some(Err(ReadError::Parse(e))) = reader.next() else {
+            panic!("expected parse error");
+        };
+        // Right after the invalid flags of the second event.
+        assert_eq!(e.offset(), 16 + 3 + 24 + 24);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn event_reader_invalid_header() {
+        let mut bytes = file_le(1, 2, &[], &[], 3, &[]);
+        bytes[2] = 0;
+        let Err(ReadError::Parse(e)) = EventReader::new(&bytes[..]) else {
+            panic!("expected parse error");
+        };
+        assert_eq!(e.offset(), 2);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn event_reader_trailing_bytes() {
+        let mut bytes = file_le(1, 2, &[], &[], 3, &[]);
+        let len = bytes.len();
+        bytes.push(0);
+
+        let mut reader = EventReader::new(&bytes[..]).unwrap();
+        let Some(Err(ReadError::Parse(e))) = reader.next() else {
+            panic!("expected parse error");
+        };
+        assert_eq!(e.offset(), len);
+        assert_eq!(e.kind(), ParseErrorKind::TrailingBytes);
+        assert_eq!(reader.final_odb(), None);
+
+        let strict = FileView::try_from_bytes(&bytes).unwrap_err();
+        assert_eq!(strict.offset(), e.offset());
+        assert_eq!(strict.kind(), e.kind());
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn read_gz_file_compressed_and_plain() {
+        use flate2::{write::GzEncoder, Compression};
+        use std::io::Write;
+
+        let event = event_le(1, 2, 3, 4, 1, &bank_16_le(*b"BANK", 1, &[0xFF; 3]));
+        let bytes = file_le(5, 6, b"initial", &event, 7, b"final");
+
+        // Two concatenated gzip members.
+        let mut compressed = Vec::new();
+        for chunk in bytes.chunks(bytes.len() / 2 + 1) {
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(chunk).unwrap();
+            compressed.extend(encoder.finish().unwrap());
+        }
+
+        let dir = std::env::temp_dir();
+        let gz_path = dir.join(format!("midasio-{}-test.mid.gz", std::process::id()));
+        let plain_path = dir.join(format!("midasio-{}-test.mid", std::process::id()));
+        std::fs::write(&gz_path, &compressed).unwrap();
+        std::fs::write(&plain_path, &bytes).unwrap();
+        let from_gz = read_gz_file(&gz_path);
+        let from_plain = read_gz_file(&plain_path);
+        std::fs::remove_file(gz_path).unwrap();
+        std::fs::remove_file(plain_path).unwrap();
+
+        assert_eq!(from_gz.unwrap(), bytes);
+        assert_eq!(from_plain.unwrap(), bytes);
+    }
+
+    #[cfg(feature = "lz4")]
+    #[test]
+    fn read_lz4_file_two_frames_and_plain() {
+        use lz4_flex::frame::FrameEncoder;
+        use std::io::Write;
+
+        let event = event_le(1, 2, 3, 4, 1, &bank_16_le(*b"BANK", 1, &[0xFF; 3]));
+        let bytes = file_le(5, 6, b"initial", &event, 7, b"final");
+
+        let mut compressed = Vec::new();
+        for chunk in bytes.chunks(bytes.len() / 2 + 1) {
+            let mut encoder = FrameEncoder::new(Vec::new());
+            encoder.write_all(chunk).unwrap();
+            compressed.extend(encoder.finish().unwrap());
+        }
+
+        let dir = std::env::temp_dir();
+        let lz4_path = dir.join(format!("midasio-{}-test.mid.lz4", std::process::id()));
+        let plain_path = dir.join(format!("midasio-{}-test-lz4.mid", std::process::id()));
+        std::fs::write(&lz4_path, &compressed).unwrap();
+        std::fs::write(&plain_path, &bytes).unwrap();
+        let from_lz4 = read_lz4_file(&lz4_path);
+        let from_plain = read_lz4_file(&plain_path);
+        std::fs::remove_file(lz4_path).unwrap();
+        std::fs::remove_file(plain_path).unwrap();
+
+        assert_eq!(from_lz4.unwrap(), bytes);
+        assert_eq!(from_plain.unwrap(), bytes);
+    }
+
+    #[test]
+    fn data_type_classification() {
+        // (data type, is_integer, is_signed, is_floating)
+        let truth_table = [
+            (DataType::U8, true, false, false),
+            (DataType::I8, true, true, false),
+            (DataType::U16, true, false, false),
+            (DataType::I16, true, true, false),
+            (DataType::U32, true, false, false),
+            (DataType::I32, true, true, false),
+            (DataType::Bool, false, false, false),
+            (DataType::F32, false, true, true),
+            (DataType::F64, false, true, true),
+            (DataType::Str, false, false, false),
+            (DataType::Array, false, false, false),
+            (DataType::Struct, false, false, false),
+            (DataType::I64, true, true, false),
+            (DataType::U64, true, false, false),
+            (DataType::Key, false, false, false),
+            (DataType::Link, false, false, false),
+        ];
+        for (data_type, is_integer, is_signed, is_floating) in truth_table {
+            // Force new variants to be added to the table above.
+            match data_type {
+                DataType::U8
+                | DataType::I8
+                | DataType::U16
+                | DataType::I16
+                | DataType::U32
+                | DataType::I32
+                | DataType::Bool
+                | DataType::F32
+                | DataType::F64
+                | DataType::Str
+                | DataType::Array
+                | DataType::Struct
+                | DataType::I64
+                | DataType::U64
+                | DataType::Key
+                | DataType::Link => {}
+            }
+            assert_eq!(data_type.is_integer(), is_integer, "{data_type:?}");
+            assert_eq!(data_type.is_signed(), is_signed, "{data_type:?}");
+            assert_eq!(data_type.is_floating(), is_floating, "{data_type:?}");
+        }
+    }
+
+    #[test]
+    fn data_type_size() {
+        let sizes = [
+            (DataType::U8, Some(1)),
+            (DataType::I8, Some(1)),
+            (DataType::U16, Some(2)),
+            (DataType::I16, Some(2)),
+            (DataType::U32, Some(4)),
+            (DataType::I32, Some(4)),
+            (DataType::Bool, Some(4)),
+            (DataType::F32, Some(4)),
+            (DataType::F64, Some(8)),
+            (DataType::Str, None),
+            (DataType::Array, None),
+            (DataType::Struct, None),
+            (DataType::I64, Some(8)),
+            (DataType::U64, Some(8)),
+            (DataType::Key, None),
+            (DataType::Link, None),
+        ];
+        for (data_type, size) in sizes {
+            assert_eq!(data_type.size(), size, "{data_type:?}");
+        }
+    }
+
+    #[test]
+    fn data_type_try_from_unsigned() {
+        for (num, data_type) in INT_DATA_TYPES {
+            assert_eq!(DataType::try_from(num), Ok(data_type));
+            assert_eq!(DataType::try_from(u32::from(num)), Ok(data_type));
+        }
+        for num in [0, 19, u16::MAX] {
+            assert_eq!(
+                DataType::try_from(num),
+                Err(TryDataTypeFromUnsignedError(()))
+            );
+        }
+        assert!(DataType::try_from(u32::MAX).is_err());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn event_view_par_iter_matches_serial() {
+        use rayon::prelude::*;
+
+        let mut banks = Vec::new();
+        for i in 0..100u8 {
+            banks.extend(bank_16_le([b'B', b'N', b'K', i], 1, &[i]));
+        }
+        let events = event_le(0, 0, 0, 0, 1, &banks);
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+        let event_view = file_view.into_iter().next().unwrap();
+
+        let serial: u32 = event_view
+            .iter()
+            .map(|bank| u32::from(bank.data()[0]))
+            .sum();
+        let parallel: u32 = event_view
+            .par_iter()
+            .map(|bank| u32::from(bank.data()[0]))
+            .sum();
+        assert_eq!(parallel, serial);
+        let owned: u32 = event_view
+            .into_par_iter()
+            .map(|bank| u32::from(bank.data()[0]))
+            .sum();
+        assert_eq!(owned, serial);
+    }
+
+    #[test]
+    fn file_view_and_event_view_len() {
+        let banks = [bank_16_le(*b"BNK0", 1, &[1]), bank_16_le(*b"BNK1", 1, &[2])].concat();
+        let events = [
+            event_le(0, 0, 0, 0, 1, &banks),
+            event_le(0, 0, 1, 0, 1, &[]),
+        ]
+        .concat();
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+        assert_eq!(file_view.len(), 2);
+        assert!(!file_view.is_empty());
+
+        let event_views: Vec<_> = file_view.iter().collect();
+        assert_eq!(event_views[0].len(), 2);
+        assert!(!event_views[0].is_empty());
+        assert_eq!(event_views[1].len(), 0);
+        assert!(event_views[1].is_empty());
+
+        let file = file_le(0, 0, b"", &[], 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+        assert_eq!(file_view.len(), 0);
+        assert!(file_view.is_empty());
+    }
+
+    #[test]
+    fn file_view_and_event_view_random_access() {
+        let banks = [bank_16_le(*b"BNK0", 1, &[1]), bank_16_le(*b"BNK1", 1, &[2])].concat();
+        let events = [
+            event_le(0, 0, 0, 0, 1, &[]),
+            event_le(0, 0, 1, 0, 1, &banks),
+        ]
+        .concat();
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        assert_eq!(file_view.get(1).unwrap().serial_number(), 1);
+        assert_eq!(file_view[0].serial_number(), 0);
+        assert!(file_view.get(2).is_none());
+
+        let event_view = &file_view[1];
+        assert_eq!(event_view.get(0).unwrap().name(), *b"BNK0");
+        assert_eq!(event_view[1].name(), *b"BNK1");
+        assert!(event_view.get(2).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn file_view_index_out_of_bounds() {
+        let file = file_le(0, 0, b"", &[], 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+        let _ = &file_view[0];
+    }
+
+    #[test]
+    fn bank_view_as_str() {
+        let banks = [
+            bank_16_le(*b"STR0", 12, b"hello\0\0\0"),
+            bank_16_le(*b"STR1", 12, b"no nul"),
+            bank_16_le(*b"STR2", 12, b""),
+            bank_16_le(*b"STR3", 12, b"\xFF\0"),
+            bank_16_le(*b"U8__", 1, b"hello"),
+        ]
+        .concat();
+        let events = event_le(0, 0, 0, 0, 1, &banks);
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+        let event_view = &file_view[0];
+
+        assert_eq!(event_view[0].as_str(), Some("hello"));
+        assert_eq!(event_view[1].as_str(), Some("no nul"));
+        assert_eq!(event_view[2].as_str(), Some(""));
+        assert_eq!(event_view[3].as_str(), None);
+        assert_eq!(event_view[4].as_str(), None);
+    }
+
+    #[test]
+    fn bank_view_strings() {
+        let banks = [
+            bank_16_le(*b"STR0", 12, b"foo\0\0\0bar\0\0\0"),
+            bank_16_le(*b"STR1", 12, b"foo\0bar"),
+            bank_16_le(*b"STR2", 12, b""),
+            bank_16_le(*b"STR3", 12, b"foo\0\xFF\0bar\0"),
+            bank_16_le(*b"U8__", 1, b"foo\0bar"),
+        ]
+        .concat();
+        let events = event_le(0, 0, 0, 0, 1, &banks);
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+        let event_view = &file_view[0];
+
+        let strings = |i: usize| event_view[i].strings().map(|s| s.collect::<Vec<_>>());
+        assert_eq!(strings(0), Some(vec![Some("foo"), Some("bar")]));
+        assert_eq!(strings(1), Some(vec![Some("foo"), Some("bar")]));
+        assert_eq!(strings(2), Some(vec![]));
+        assert_eq!(strings(3), Some(vec![Some("foo"), None, Some("bar")]));
+        assert_eq!(strings(4), None);
+    }
+
+    #[test]
+    fn size_bytes_matches_input_length() {
+        let event_16 = event_le(0, 0, 0, 0, 1, &bank_16_le(*b"BANK", 1, &[1, 2, 3]));
+        let event_32 = event_le(0, 0, 1, 0, 17, &bank_32_le(*b"BANK", 1, &[1; 9]));
+        let event_32a = event_le(0, 0, 2, 0, 49, &bank_32a_le(*b"BANK", 1, &[1; 16]));
+        let empty = event_le(0, 0, 3, 0, 1, &[]);
+        let events = [&event_16[..], &event_32, &event_32a, &empty].concat();
+        let file = file_le(0, 0, b"initial odb", &events, 0, b"final");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        assert_eq!(file_view[0].size_bytes(), event_16.len());
+        assert_eq!(file_view[1].size_bytes(), event_32.len());
+        assert_eq!(file_view[2].size_bytes(), event_32a.len());
+        assert_eq!(file_view[3].size_bytes(), empty.len());
+        assert_eq!(file_view.size_bytes(), file.len());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_datetimes() {
+        let events = [
+            event_le(0, 0, 0, 0, 1, &[]),
+            event_le(0, 0, 0, u32::MAX, 1, &[]),
+        ]
+        .concat();
+        let file = file_le(0, 1_700_000_000, b"", &events, u32::MAX, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        assert_eq!(file_view.initial_datetime().timestamp(), 1_700_000_000);
+        assert_eq!(file_view[0].datetime(), chrono::DateTime::UNIX_EPOCH);
+        assert_eq!(file_view[1].datetime().timestamp(), i64::from(u32::MAX));
+        assert_eq!(file_view.final_datetime().timestamp(), i64::from(u32::MAX));
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_offset_datetimes() {
+        let events = [
+            event_le(0, 0, 0, 0, 1, &[]),
+            event_le(0, 0, 0, u32::MAX, 1, &[]),
+        ]
+        .concat();
+        let file = file_le(0, 1_700_000_000, b"", &events, u32::MAX, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        assert_eq!(
+            file_view.initial_offset_datetime().unix_timestamp(),
+            1_700_000_000
+        );
+        assert_eq!(
+            file_view[0].offset_datetime(),
+            time::OffsetDateTime::UNIX_EPOCH
+        );
+        assert_eq!(
+            file_view[1].offset_datetime().unix_timestamp(),
+            i64::from(u32::MAX)
+        );
+        assert_eq!(
+            file_view.final_offset_datetime().unix_timestamp(),
+            i64::from(u32::MAX)
+        );
+    }
+
+    #[test]
+    fn file_view_try_from_bytes_lenient_valid() {
+        let events = [event_le(0, 0, 0, 0, 1, &[]), event_le(0, 0, 1, 0, 1, &[])].concat();
+        let file = file_le(1, 2, b"initial", &events, 3, b"final");
+
+        let (file_view, recovery) = FileView::try_from_bytes_lenient(&file).unwrap();
+        assert!(recovery.is_none());
+        assert!(file_view
+            .diff(&FileView::try_from_bytes(&file).unwrap())
+            .is_empty());
+    }
+
+    #[test]
+    fn file_view_try_from_bytes_lenient_truncated_event() {
+        let banks = bank_16_le(*b"BANK", 1, &[0xFF; 16]);
+        let events = [
+            event_le(0, 0, 0, 0, 1, &banks),
+            event_le(0, 0, 1, 0, 1, &banks),
+        ]
+        .concat();
+        let file = file_le(1, 2, b"initial", &events, 3, b"final");
+        let truncated_len = 16 + 7 + events.len() - 10;
+        let file = &file[..truncated_len];
+        assert!(FileView::try_from_bytes(file).is_err());
+
+        let (file_view, recovery) = FileView::try_from_bytes_lenient(file).unwrap();
+        assert_eq!(file_view.len(), 1);
+        assert_eq!(file_view.run_number(), 1);
+        assert_eq!(file_view.initial_odb(), b"initial");
+        assert_eq!(file_view.final_timestamp(), 0);
+        assert_eq!(file_view.final_odb(), b"");
+
+        let recovery = recovery.unwrap();
+        let offset = 16 + 7 + events.len() / 2;
+        assert_eq!(recovery.offset(), offset);
+        assert_eq!(recovery.discarded_bytes(), truncated_len - offset);
+        assert!(!recovery.has_final_odb());
+    }
+
+    #[test]
+    fn file_view_try_from_bytes_lenient_missing_final_odb() {
+        let events = event_le(0, 0, 0, 0, 1, &[]);
+        let file = file_le(1, 2, b"initial", &events, 3, b"final");
+        let file = &file[..16 + 7 + events.len()];
+
+        let (file_view, recovery) = FileView::try_from_bytes_lenient(file).unwrap();
+        assert_eq!(file_view.len(), 1);
+        let recovery = recovery.unwrap();
+        assert_eq!(recovery.offset(), file.len());
+        assert_eq!(recovery.discarded_bytes(), 0);
+        assert!(!recovery.has_final_odb());
+    }
+
+    #[test]
+    fn file_view_try_from_bytes_lenient_trailing_bytes() {
+        let mut file = file_le(1, 2, b"initial", &[], 3, b"final");
+        let len = file.len();
+        file.extend([0xFF; 5]);
+
+        let (file_view, recovery) = FileView::try_from_bytes_lenient(&file).unwrap();
+        assert_eq!(file_view.final_timestamp(), 3);
+        assert_eq!(file_view.final_odb(), b"final");
+        let recovery = recovery.unwrap();
+        assert_eq!(recovery.offset(), len);
+        assert_eq!(recovery.discarded_bytes(), 5);
+        assert!(recovery.has_final_odb());
+    }
+
+    #[test]
+    fn file_view_try_from_bytes_lenient_final_odb_like_an_event() {
+        // The final header followed by this ODB dump is also a valid empty event.
+        let final_odb = [0, 0, 0, 0, 1, 0, 0, 0];
+        let events = event_le(0, 0, 0, 0, 1, &[]);
+        let file = file_le(1, 2, b"initial", &events, 3, &final_odb);
+
+        let (file_view, recovery) = FileView::try_from_bytes_lenient(&file).unwrap();
+        assert!(recovery.is_none());
+        assert_eq!(file_view.len(), 1);
+        assert_eq!(file_view.final_timestamp(), 3);
+        assert_eq!(file_view.final_odb(), final_odb);
+        assert!(file_view
+            .diff(&FileView::try_from_bytes(&file).unwrap())
+            .is_empty());
+    }
+
+    #[test]
+    fn file_view_try_from_bytes_lenient_invalid_initial_odb() {
+        let file = file_le(1, 2, b"initial", &[], 3, b"final");
+        assert!(FileView::try_from_bytes_lenient(&file[..20]).is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn event_view_byte_offset() {
+        let banks = bank_16_le(*b"BANK", 1, &[0xFF; 3]);
+        let event_0 = event_le(0, 0, 0, 0, 1, &banks);
+        let event_1 = event_le(0, 0, 1, 0, 1, &[]);
+        let event_2 = event_le(0, 0, 2, 0, 1, &banks);
+        let events = [&event_0[..], &event_1, &event_2].concat();
+        let file = file_le(0, 0, b"initial odb", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        let offset_0 = 16 + 11;
+        let offset_1 = offset_0 + event_0.len();
+        let offset_2 = offset_1 + event_1.len();
+        let offsets: Vec<_> = file_view.iter().map(EventView::byte_offset).collect();
+        assert_eq!(offsets, [offset_0, offset_1, offset_2]);
+        for event_view in &file_view {
+            let offset = event_view.byte_offset();
+            assert_eq!(&file[offset..][..event_view.size_bytes()], event_view.bytes);
+            assert_eq!(event_view.to_owned_event().byte_offset(), offset);
+            assert_eq!(event_view.to_owned_event().view().byte_offset(), offset);
+        }
+
+        let (file_view, _) = FileView::try_from_bytes_lenient(&file).unwrap();
+        let lenient: Vec<_> = file_view.iter().map(EventView::byte_offset).collect();
+        assert_eq!(lenient, offsets);
+
+        let reader: Vec<_> = EventReader::new(&file[..])
+            .unwrap()
+            .map(|event| event.unwrap().byte_offset())
+            .collect();
+        assert_eq!(reader, offsets);
+    }
+
+    #[test]
+    fn bank_view_data_len_elements() {
+        let banks = [
+            bank_16_le(*b"U8__", 1, &[0xFF; 5]),
+            bank_16_le(*b"F64_", 10, &[0xFF; 24]),
+            bank_16_le(*b"STR_", 12, b"hello\0"),
+            bank_16_le(*b"EMPT", 6, &[]),
+        ]
+        .concat();
+        let events = event_le(0, 0, 0, 0, 1, &banks);
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+        let event_view = &file_view[0];
+
+        assert_eq!(event_view[0].data_len_elements(), 5);
+        assert_eq!(event_view[1].data_len_elements(), 3);
+        assert_eq!(event_view[2].data_len_elements(), 6);
+        assert_eq!(event_view[3].data_len_elements(), 0);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn parse_error_event_index_and_bank_name() {
+        let good = event_le(0, 0, 0, 0, 1, &bank_16_le(*b"ADC0", 1, &[1]));
+        let bad_banks = [
+            bank_16_le(*b"ADC1", 1, &[1]),
+            bank_16_le(*b"ADC2", 99, &[1]),
+        ]
+        .concat();
+        let bad = event_le(0, 0, 1, 0, 1, &bad_banks);
+        let events = [&good[..], &good, &bad, &good].concat();
+        let file = file_le(0, 0, b"", &events, 0, b"");
+
+        let error = FileView::try_from_bytes(&file).unwrap_err();
+        assert_eq!(error.event_index(), Some(2));
+        assert_eq!(error.bank_name(), Some(*b"ADC2"));
+        assert!(error.to_string().contains("in event `2` at bank `ADC2`"));
+
+        let error = EventReader::new(&file[..])
+            .unwrap()
+            .find_map(Result::err)
+            .unwrap();
+        let ReadError::Parse(error) = error else {
+            panic!("expected parse error");
+        };
+        assert_eq!(error.event_index(), Some(2));
+        assert_eq!(error.bank_name(), Some(*b"ADC2"));
+    }
+
+    #[test]
+    fn parse_error_event_index_without_bank_name() {
+        // Invalid flags.
+        let events = [event_le(0, 0, 0, 0, 1, &[]), event_le(0, 0, 1, 0, 2, &[])].concat();
+        let file = file_le(0, 0, b"", &events, 0, b"");
+
+        let error = FileView::try_from_bytes(&file).unwrap_err();
+        assert_eq!(error.event_index(), Some(1));
+        assert_eq!(error.bank_name(), None);
+    }
+
+    #[test]
+    fn parse_error_outside_events() {
+        let events = event_le(0, 0, 0, 0, 1, &[]);
+        let mut file = file_le(0, 0, b"", &events, 0, b"final");
+        file.pop();
+        let error = FileView::try_from_bytes(&file).unwrap_err();
+        assert_eq!(error.event_index(), None);
+        assert_eq!(error.bank_name(), None);
+
+        let mut file = file_le(0, 0, b"", &events, 0, b"");
+        file[2] = 0;
+        let error = FileView::try_from_bytes(&file).unwrap_err();
+        assert_eq!(error.event_index(), None);
+        assert_eq!(error.bank_name(), None);
+    }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn mmap_file_view() {
+        let event = event_le(1, 2, 3, 4, 1, &bank_16_le(*b"BANK", 1, &[0xFF; 3]));
+        let bytes = file_le(5, 6, b"initial", &event, 7, b"final");
+        let path = std::env::temp_dir().join(format!("midasio-{}-mmap.mid", std::process::id()));
+        std::fs::write(&path, &bytes).unwrap();
+
+        // SAFETY: Nothing else modifies this temporary file.
+        let file = unsafe { MmapFile::open(&path) }.unwrap();
+        assert_eq!(&file[..], &bytes[..]);
+        let file_view = file.view().unwrap();
+        assert_eq!(file_view.run_number(), 5);
+        assert_eq!(file_view[0].serial_number(), 3);
+        drop(file);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn file_view_indexed() {
+        let events = [
+            event_le(0, 0, 10, 0, 1, &[]),
+            event_le(0, 0, 11, 0, 1, &[]),
+            event_le(0, 0, 12, 0, 1, &[]),
+        ]
+        .concat();
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        let mut indexed = file_view.indexed();
+        assert_eq!(indexed.len(), 3);
+        let (index, event_view) = indexed.next_back().unwrap();
+        assert_eq!((index, event_view.serial_number()), (2, 12));
+        let (index, event_view) = indexed.next().unwrap();
+        assert_eq!((index, event_view.serial_number()), (0, 10));
+        assert_eq!(indexed.len(), 1);
+
+        for (index, event_view) in file_view.indexed().rev() {
+            assert_eq!(event_view.serial_number(), 10 + index as u32);
+        }
+    }
+
+    #[test]
+    fn file_view_try_from_bytes_with_optional_final_odb() {
+        let events = event_le(0, 0, 0, 0, 1, &[]);
+        let file = file_le(1, 2, b"initial", &events, 3, b"final");
+        let no_final = &file[..16 + 7 + events.len()];
+        let options = FileViewOptions::new().require_final_odb(false);
+
+        assert!(FileView::try_from_bytes(no_final).is_err());
+        let file_view = FileView::try_from_bytes_with(no_final, options).unwrap();
+        assert_eq!(file_view.len(), 1);
+        assert!(!file_view.has_final_odb());
+        assert_eq!(file_view.final_timestamp(), 0);
+        assert_eq!(file_view.final_odb(), b"");
+        assert_eq!(file_view.size_bytes(), no_final.len());
+
+        let file_view = FileView::try_from_bytes_with(&file, options).unwrap();
+        assert!(file_view.has_final_odb());
+        assert_eq!(file_view.final_timestamp(), 3);
+        assert_eq!(file_view.final_odb(), b"final");
+
+        // A present but invalid final ODB dump is still an error.
+        assert!(FileView::try_from_bytes_with(&file[..file.len() - 1], options).is_err());
+    }
+
+    #[test]
+    fn file_view_try_from_bytes_with_run_number_mismatch() {
+        let mut file = file_le(1, 2, b"", &[], 3, b"");
+        let final_run_number = file.len() - 12;
+        file[final_run_number..][..4].copy_from_slice(&2u32.to_le_bytes());
+        let options = FileViewOptions::new().require_run_number_match(false);
+
+        assert!(FileView::try_from_bytes(&file).is_err());
+        assert!(FileView::try_from_bytes_with(&file, FileViewOptions::default()).is_err());
+        let file_view = FileView::try_from_bytes_with(&file, options).unwrap();
+        assert_eq!(file_view.run_number(), 1);
+        assert!(file_view.has_final_odb());
+    }
+
+    #[test]
+    fn bank_view_parse_struct() {
+        let data = [1, 0, 10, 0, 0, 0, 2, 0, 20, 0, 0, 0, 3];
+        let banks = bank_16_le(*b"STRC", 14, &data);
+        let events = event_le(0, 0, 0, 0, 1, &banks);
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+        let bank_view = file_view[0][0];
+
+        let records: Vec<_> = bank_view
+            .parse_struct(6, |record| {
+                let channel = u16::from_le_bytes(record[..2].try_into().unwrap());
+                let value = u32::from_le_bytes(record[2..].try_into().unwrap());
+                (channel, value)
+            })
+            .collect();
+        assert_eq!(records, [(1, 10), (2, 20)]);
+        assert_eq!(bank_view.parse_struct(0, |record| record.len()).count(), 0);
+        assert_eq!(
+            bank_view.parse_struct(100, |record| record.len()).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn bank_view_eq_and_hash() {
+        use std::collections::HashSet;
+
+        let events = [
+            event_le(0, 0, 0, 0, 1, &bank_16_le(*b"BANK", 1, &[1, 2, 3])),
+            event_le(0, 0, 1, 0, 17, &bank_32_le(*b"BANK", 1, &[1, 2, 3])),
+            event_le(0, 0, 2, 0, 1, &bank_16_le(*b"BANK", 1, &[1, 2, 4])),
+            event_le(0, 0, 3, 0, 1, &bank_16_le(*b"BANK", 2, &[1, 2, 3])),
+            event_le(0, 0, 4, 0, 1, &bank_16_le(*b"ABCD", 1, &[1, 2, 3])),
+        ]
+        .concat();
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        // Same content from a different header format and location.
+        assert_eq!(file_view[0][0], file_view[1][0]);
+        assert_ne!(file_view[0][0], file_view[2][0]);
+        assert_ne!(file_view[0][0], file_view[3][0]);
+        assert_ne!(file_view[0][0], file_view[4][0]);
+
+        let unique: HashSet<_> = file_view.iter().map(|event| event[0]).collect();
+        assert_eq!(unique.len(), 4);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn file_view_bank_name_counts() {
+        let banks = [
+            bank_16_le(*b"ADC0", 1, &[1]),
+            bank_16_le(*b"ADC0", 1, &[2]),
+            bank_16_le(*b"TDC0", 1, &[3]),
+        ]
+        .concat();
+        let events = [
+            event_le(0, 0, 0, 0, 1, &banks),
+            event_le(0, 0, 1, 0, 1, &[]),
+            event_le(0, 0, 2, 0, 1, &bank_16_le(*b"TDC0", 1, &[4])),
+        ]
+        .concat();
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        let counts = file_view.bank_name_counts();
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts[b"ADC0"], 2);
+        assert_eq!(counts[b"TDC0"], 2);
+        assert_eq!(file_view.bank_name_count(*b"ADC0"), 2);
+        assert_eq!(file_view.bank_name_count(*b"NONE"), 0);
+    }
+
+    #[test]
+    fn event_view_all_banks_bytes() {
+        let banks = [
+            bank_32a_le(*b"BNK0", 1, &[1, 2, 3]),
+            bank_32a_le(*b"BNK1", 6, &[4; 8]),
+        ]
+        .concat();
+        let events = [
+            event_le(0, 0, 0, 0, 49, &banks),
+            event_le(0, 0, 1, 0, 49, &[]),
+        ]
+        .concat();
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        assert_eq!(file_view[0].all_banks_bytes(), banks);
+        assert!(file_view[1].all_banks_bytes().is_empty());
+    }
+
+    #[test]
+    fn file_view_zero_bank_events_between_events() {
+        let banks = bank_16_le(*b"BANK", 1, &[1, 2, 3]);
+        let events = [
+            event_le(1, 0, 0, 0, 1, &banks),
+            event_le(2, 0, 1, 0, 1, &[]),
+            event_le(1, 0, 2, 0, 1, &banks),
+            event_le(2, 0, 3, 0, 1, &[]),
+        ]
+        .concat();
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        let bank_counts: Vec<_> = file_view.iter().map(EventView::len).collect();
+        assert_eq!(bank_counts, [1, 0, 1, 0]);
+        assert_eq!(file_view[1].size_bytes(), 24);
+        assert_eq!(file_view[1].metadata().payload_len, 0);
+    }
+
+    #[test]
+    fn file_view_invalid_event_size_le() {
+        for event_size in [0u32, 4, 7] {
+            let mut event = event_le(1, 0, 0, 0, 1, &[]);
+            event[12..16].copy_from_slice(&event_size.to_le_bytes());
+            let file = file_le(0, 0, b"", &event, 0, b"");
+            assert!(FileView::try_from_bytes(&file).is_err());
+        }
+    }
+
+    #[test]
+    fn file_view_odb_str() {
+        let file = file_le(0, 0, br#"{"key": "value"}"#, &[], 0, b"\xFF\xFE");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        assert_eq!(file_view.initial_odb_str(), Some(r#"{"key": "value"}"#));
+        assert_eq!(file_view.final_odb_str(), None);
+    }
+
+    #[test]
+    fn odb_format_detect() {
+        let cases: [(&[u8], OdbFormat); 12] = [
+            (b"<?xml version=\"1.0\"?>\n<odb>", OdbFormat::Xml),
+            (b"\n  <odb>", OdbFormat::Xml),
+            (b"{\n  \"/MIDAS version\" : \"2.1\"\n}", OdbFormat::Json),
+            (b"[1, 2]", OdbFormat::Json),
+            (b"[ /System]", OdbFormat::Ascii),
+            (b"[/System]\nClients = ...", OdbFormat::Ascii),
+            (b"\xEF\xBB\xBF{}", OdbFormat::Json),
+            (b"", OdbFormat::Unknown),
+            (b"   \n\t", OdbFormat::Unknown),
+            (b"[", OdbFormat::Json),
+            (b"\x00\x80\xFF", OdbFormat::Unknown),
+            (b"plain text", OdbFormat::Unknown),
+        ];
+        for (odb, format) in cases {
+            assert_eq!(OdbFormat::detect(odb), format, "{}", odb.escape_ascii());
+        }
+
+        let file = file_le(0, 0, b"<odb/>", &[], 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+        assert_eq!(file_view.initial_odb_format(), OdbFormat::Xml);
+        assert_eq!(file_view.final_odb_format(), OdbFormat::Unknown);
+    }
+
+    #[test]
+    fn bank_view_iter_as() {
+        let mut banks = bank_16_le(*b"U16_", 4, &[1, 0, 0xFF, 0xFF]);
+        banks.extend(bank_16_le(*b"I64_", 17, &(-3i64).to_le_bytes()));
+        let events = event_le(0, 0, 0, 0, 1, &banks);
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+        let event_view = file_view.iter().next().unwrap();
+
+        let bank_view = event_view.bank(b"U16_").unwrap();
+        let values = bank_view.iter_as::<u16>(Endianness::Little).unwrap();
+        assert_eq!(values.collect::<Vec<_>>(), [1, u16::MAX]);
+        let values = bank_view.iter_as::<u16>(Endianness::Big).unwrap();
+        assert_eq!(values.collect::<Vec<_>>(), [256, u16::MAX]);
+        assert!(bank_view.iter_as::<u32>(Endianness::Little).is_none());
+        assert!(bank_view.iter_as::<i16>(Endianness::Little).is_none());
+
+        let bank_view = event_view.bank(b"I64_").unwrap();
+        let values = bank_view.iter_as::<i64>(Endianness::Little).unwrap();
+        assert_eq!(values.collect::<Vec<_>>(), [-3]);
+        assert!(bank_view.iter_as::<u64>(Endianness::Little).is_none());
+    }
+
+    #[test]
+    fn event_view_inconsistent_banks_size() {
+        let mut banks = bank_16_le(*b"BNK0", 1, &[0; 8]);
+        banks.extend(bank_16_le(*b"BNK1", 1, &[0; 8]));
+        let mut event = event_le(0, 0, 0, 0, 1, &banks);
+        // Claim that the event ends in the middle of the second bank.
+        event[12..16].copy_from_slice(&28u32.to_le_bytes());
+        event[16..20].copy_from_slice(&20u32.to_le_bytes());
+        let error = parse::event_view(Endianness::Little, FileViewOptions::new())
+            .parse(&event[..44])
+            .unwrap_err();
+        assert!(error
+            .inner()
+            .to_string()
+            .contains("invalid size of all banks"));
+
+        let file = file_le(0, 0, b"", &event, 0, b"");
+        let error = FileView::try_from_bytes(&file).unwrap_err();
+        assert_eq!(error.kind(), ParseErrorKind::EventSizeMismatch);
+        assert_eq!(error.event_index(), Some(0));
+        assert!(error.to_string().contains("invalid size of all banks"));
+        let try_events_error = try_events(&file).find_map(Result::err).unwrap();
+        assert_eq!(try_events_error.kind(), ParseErrorKind::EventSizeMismatch);
+        assert_eq!(error.offset(), try_events_error.offset());
+    }
+
+    // Small xorshift generator to produce reproducible "random" inputs without
+    // an extra dependency.
+    fn xorshift(state: &mut u64) -> u64 {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        *state
+    }
+
+    fn random_inputs() -> Vec<Vec<u8>> {
+        let mut banks = bank_16_le(*b"ADC0", 4, &[1, 0, 2, 0]);
+        banks.extend(bank_16_le(*b"STR0", 12, b"hi\0"));
+        let mut events = event_le(1, 0, 0, 0, 1, &banks);
+        events.extend(event_le(
+            2,
+            0,
+            1,
+            0,
+            49,
+            &bank_32a_le(*b"U64_", 18, &[0; 16]),
+        ));
+        let valid = file_le(1, 0, b"odb", &events, 0, b"odb");
+
+        let mut state = 0x2545_F491_4F6C_DD1D;
+        let mut inputs = Vec::new();
+        for _ in 0..2000 {
+            let len = (xorshift(&mut state) % 128) as usize;
+            inputs.push((0..len).map(|_| xorshift(&mut state) as u8).collect());
+
+            let mut mutated = valid.clone();
+            for _ in 0..=(xorshift(&mut state) % 4) {
+                let i = (xorshift(&mut state) as usize) % mutated.len();
+                mutated[i] = xorshift(&mut state) as u8;
+            }
+            mutated.truncate(1 + (xorshift(&mut state) as usize) % valid.len());
+            inputs.push(mutated);
+        }
+        inputs
+    }
+
+    #[test]
+    fn random_input_never_panics() {
+        for input in random_inputs() {
+            let _ = run_number_unchecked(&input);
+            let _ = initial_timestamp_unchecked(&input);
+            let _ = FileView::try_from_bytes_lenient(&input);
+            let _ = try_events(&input).count();
+            let _ = try_lazy_events(&input)
+                .flatten()
+                .map(|event_view| event_view.banks().count())
+                .sum::<usize>();
+            let _ = expected_file_len(&input);
+            let _ = file_endianness_unchecked(&input);
+            let _ = FileView::try_from_bytes_with(
+                &input,
+                FileViewOptions::new()
+                    .require_final_odb(false)
+                    .require_run_number_match(false),
+            );
+            let _ = FileView::try_from_bytes_with(
+                &input,
+                FileViewOptions::new()
+                    .max_event_size(64)
+                    .max_bank_data_size(16),
+            );
+            if let Ok(file_view) = FileView::try_from_bytes(&input) {
+                for bank_view in file_view.iter().flat_map(EventView::iter) {
+                    let _ = bank_view.decode_any();
+                    let _ = bank_view.as_str_lossy();
+                    let _ = bank_view.try_iter_u32().count();
+                }
+            }
+            #[cfg(feature = "std")]
+            if let Ok(reader) = EventReader::new(&input[..]) {
+                for event in reader.flatten() {
+                    let _ = event.view();
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn event_view_banks() {
+        let mut banks = bank_16_le(*b"BNK0", 1, &[1]);
+        banks.extend(bank_16_le(*b"BNK1", 1, &[2]));
+        let events = event_le(0, 0, 0, 0, 1, &banks);
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        let event_view = file_view.iter().next().unwrap();
+        let banks = event_view.banks();
+        assert_eq!(banks.len(), 2);
+        assert_eq!(banks[1].data(), [2]);
+        let index = banks.binary_search_by_key(b"BNK1", |bank_view| bank_view.name());
+        assert_eq!(index, Ok(1));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn file_open_view() {
+        let event = event_le(1, 2, 3, 4, 1, &bank_16_le(*b"BANK", 1, &[0xFF; 3]));
+        let bytes = file_le(5, 6, b"initial", &event, 7, b"final");
+        let path = std::env::temp_dir().join(format!("midasio-{}-file.mid", std::process::id()));
+        std::fs::write(&path, &bytes).unwrap();
+        let file = File::open(&path);
+        std::fs::remove_file(path).unwrap();
+
+        let file = file.unwrap();
+        assert_eq!(&file[..], &bytes[..]);
+        let file_view = file.view();
+        assert_eq!(file_view.run_number(), 5);
+        assert_eq!(file_view[0].serial_number(), 3);
+
+        let path = std::env::temp_dir().join(format!("midasio-{}-bad.mid", std::process::id()));
+        std::fs::write(&path, &bytes[..bytes.len() - 1]).unwrap();
+        let result = File::open(&path);
+        std::fs::remove_file(path).unwrap();
+        assert!(matches!(result, Err(ReadError::Parse(_))));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn file_view_matches_file_view_try_from_bytes() {
+        let events = [
+            event_le(1, 0, 0, 0, 1, &bank_16_le(*b"BANK", 1, &[1, 2, 3])),
+            event_le(2, 0, 1, 0, 17, &bank_32_le(*b"BANK", 4, &[1, 2])),
+        ]
+        .concat();
+        let bytes = file_le(5, 6, b"initial", &events, 7, b"final");
+        let expected = FileView::try_from_bytes(&bytes).unwrap();
+
+        let file = File::try_from(bytes.clone()).unwrap();
+        let file_view = file.view();
+        assert_eq!(file_view.run_number(), expected.run_number());
+        assert_eq!(file_view.initial_timestamp(), expected.initial_timestamp());
+        assert_eq!(file_view.initial_odb(), expected.initial_odb());
+        assert_eq!(file_view.final_run_number(), expected.final_run_number());
+        assert_eq!(file_view.final_timestamp(), expected.final_timestamp());
+        assert_eq!(file_view.final_odb(), expected.final_odb());
+        assert_eq!(file_view.endianness(), expected.endianness());
+        assert!(file_view.diff(&expected).is_empty());
+        for (event_view, expected) in file_view.iter().zip(&expected) {
+            assert_eq!(event_view.byte_offset(), expected.byte_offset());
+            assert_eq!(event_view.all_banks_bytes(), expected.all_banks_bytes());
+        }
+
+        assert!(File::try_from(bytes[1..].to_vec()).is_err());
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn file_open_gz() {
+        use flate2::{write::GzEncoder, Compression};
+        use std::io::Write;
+
+        let bytes = file_le(5, 6, b"initial", &[], 7, b"final");
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&bytes).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        let path = std::env::temp_dir().join(format!("midasio-{}-file.mid.gz", std::process::id()));
+        std::fs::write(&path, &compressed).unwrap();
+        let file = File::open(&path);
+        std::fs::remove_file(path).unwrap();
+
+        assert_eq!(file.unwrap().view().run_number(), 5);
+    }
+
+    #[test]
+    fn bank_view_unrestricted_name() {
+        let mut banks = bank_16_le(*b"A_B ", 1, &[1]);
+        banks.extend(bank_16_le(*b"$\xFF\x00.", 1, &[2]));
+        let events = event_le(0, 0, 0, 0, 1, &banks);
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        let event_view = file_view.iter().next().unwrap();
+        assert_eq!(event_view.bank(b"A_B ").unwrap().data(), [1]);
+        assert_eq!(event_view[1].name(), *b"$\xFF\x00.");
+    }
+
+    #[test]
+    fn data_type_display() {
+        let names = [
+            (DataType::U8, "TID_UINT8"),
+            (DataType::I8, "TID_INT8"),
+            (DataType::U16, "TID_UINT16"),
+            (DataType::I16, "TID_INT16"),
+            (DataType::U32, "TID_UINT32"),
+            (DataType::I32, "TID_INT32"),
+            (DataType::Bool, "TID_BOOL"),
+            (DataType::F32, "TID_FLOAT"),
+            (DataType::F64, "TID_DOUBLE"),
+            (DataType::Str, "TID_STRING"),
+            (DataType::Array, "TID_ARRAY"),
+            (DataType::Struct, "TID_STRUCT"),
+            (DataType::I64, "TID_INT64"),
+            (DataType::U64, "TID_UINT64"),
+            (DataType::Key, "TID_KEY"),
+            (DataType::Link, "TID_LINK"),
+        ];
+        for (data_type, name) in names {
+            assert_eq!(data_type.to_string(), name);
+            assert_ne!(format!("{data_type:?}"), name);
+        }
+    }
+
+    #[test]
+    fn bank_view_data_split_last() {
+        let events = event_le(0, 0, 0, 0, 1, &bank_16_le(*b"BANK", 1, &[1, 2, 3, 4, 5]));
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+        let bank_view = &file_view[0][0];
+
+        let (payload, trailer) = bank_view.data_split_last(4).unwrap();
+        assert_eq!(payload, [1]);
+        assert_eq!(trailer, [2, 3, 4, 5]);
+        assert_eq!(bank_view.data_split_last(0).unwrap().1, [] as [u8; 0]);
+        assert_eq!(bank_view.data_split_last(5).unwrap().0, [] as [u8; 0]);
+        assert!(bank_view.data_split_last(6).is_none());
+    }
+
+    #[test]
+    fn try_events_valid() {
+        let events = [
+            event_le(1, 0, 0, 0, 1, &bank_16_le(*b"ADC0", 1, &[1])),
+            event_le(2, 0, 1, 0, 1, &[]),
+        ]
+        .concat();
+        let file = file_le(0, 0, b"odb", &events, 0, b"odb");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        let event_views = try_events(&file).collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(event_views.len(), 2);
+        for (a, b) in event_views.iter().zip(&file_view) {
+            assert_eq!(a.id(), b.id());
+            assert_eq!(a.byte_offset(), b.byte_offset());
+            assert_eq!(a.bank_slices(*b"ADC0"), b.bank_slices(*b"ADC0"));
+        }
+    }
+
+    #[test]
+    fn try_events_invalid_event() {
+        let good = event_le(0, 0, 0, 0, 1, &bank_16_le(*b"ADC0", 1, &[1]));
+        let bad = event_le(0, 0, 1, 0, 1, &bank_16_le(*b"ADC1", 99, &[1]));
+        let events = [&good[..], &bad, &good].concat();
+        let file = file_le(0, 0, b"", &events, 0, b"");
+
+        let mut iter = try_events(&file);
+        assert!(iter.next().unwrap().is_ok());
+        let error = iter.next().unwrap().unwrap_err();
+        assert_eq!(error.event_index(), Some(1));
+        assert_eq!(error.bank_name(), Some(*b"ADC1"));
+        assert!(error.offset() >= 16 + good.len());
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn try_events_invalid_file() {
+        let event = event_le(0, 0, 0, 0, 1, &[]);
+        let file = file_le(0, 0, b"", &event, 0, b"odb");
+
+        let mut iter = try_events(&file[..file.len() - 1]);
+        assert!(iter.next().unwrap().is_ok());
+        let error = iter.next().unwrap().unwrap_err();
+        assert_eq!(error.event_index(), None);
+        assert!(iter.next().is_none());
+
+        let mut iter = try_events(&file[..16 + event.len()]);
+        assert!(iter.next().unwrap().is_ok());
+        assert!(iter.next().unwrap().is_err());
+
+        let mut iter = try_events(&file[..10]);
+        assert!(iter.next().unwrap().is_err());
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn end_of_run_marker_event() {
+        let mut events = event_le(1, 0, 0, 0, 1, &bank_16_le(*b"ADC0", 1, &[1]));
+        events.extend(event_le(EOR_ID, 0, 1, 0, 1, &[]));
+        let file = file_le(1, 0, b"odb", &events, 0, b"final");
+
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+        assert_eq!(file_view.len(), 2);
+        assert!(!file_view[0].is_end_of_run_marker());
+        assert!(file_view[1].is_end_of_run_marker());
+        assert_eq!(file_view.final_odb(), b"final");
+
+        let event_views = try_events(&file).collect::<Result<Vec<_>, _>>().unwrap();
+        assert!(event_views[1].is_end_of_run_marker());
+
+        #[cfg(feature = "std")]
+        {
+            let mut reader = EventReader::new(&file[..]).unwrap();
+            let events = reader.by_ref().collect::<Result<Vec<_>, _>>().unwrap();
+            assert!(events[1].view().is_end_of_run_marker());
+            assert_eq!(reader.final_odb(), Some(&b"final"[..]));
+        }
+    }
+
+    #[test]
+    fn constants_match_layout() {
+        let event = event_le(0, 0, 0, 0, 1, &[]);
+        let file = file_le(0, 0, b"", &event, 0, b"");
+        assert_eq!(event.len(), EVENT_HEADER_LENGTH + ALL_BANKS_HEADER_LENGTH);
+        assert_eq!(file.len(), 2 * ODB_HEADER_LENGTH + event.len());
+        assert_eq!(&file[2..4], b"MI");
+        assert_eq!(bank_16_le(*b"BANK", 1, &[]).len(), BANK_16_HEADER_LENGTH);
+        assert_eq!(bank_32_le(*b"BANK", 1, &[]).len(), BANK_32_HEADER_LENGTH);
+        assert_eq!(bank_32a_le(*b"BANK", 1, &[]).len(), BANK_32A_HEADER_LENGTH);
+    }
+
+    #[test]
+    fn bank_view_data_type_raw() {
+        for (n, data_type) in INT_DATA_TYPES {
+            let events = event_le(0, 0, 0, 0, 1, &bank_16_le(*b"BANK", n, &[]));
+            let file = file_le(0, 0, b"", &events, 0, b"");
+            let file_view = FileView::try_from_bytes(&file).unwrap();
+            let bank_view = &file_view[0][0];
+            assert_eq!(bank_view.data_type(), data_type);
+            assert_eq!(bank_view.data_type_raw(), u32::from(n));
+        }
+
+        let events = event_le(0, 0, 0, 0, 17, &bank_32_le(*b"BANK", 16, b"link"));
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+        assert_eq!(file_view[0][0].data_type_raw(), 16);
+    }
+
+    #[test]
+    fn bank_view_key_and_link() {
+        let mut banks = bank_16_le(*b"KEY_", 15, b"/Runinfo\0");
+        banks.extend(bank_16_le(*b"LINK", 16, b"/Runinfo/State\0\0"));
+        let events = event_le(0, 0, 0, 0, 1, &banks);
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        let key = &file_view[0][0];
+        assert_eq!(key.data_type(), DataType::Key);
+        assert_eq!(key.as_str(), Some("/Runinfo"));
+        let link = &file_view[0][1];
+        assert_eq!(link.data_type(), DataType::Link);
+        assert_eq!(link.as_str_lossy().unwrap(), "/Runinfo/State");
+        assert_eq!(
+            link.decode_any().downcast_ref::<String>().unwrap(),
+            "/Runinfo/State"
+        );
+
+        let bytes = write::FileBuilder::new(0)
+            .event((&file_view[0]).into())
+            .build()
+            .unwrap();
+        let file_view = FileView::try_from_bytes(&bytes).unwrap();
+        assert_eq!(file_view[0][0].data_type_raw(), 15);
+        assert_eq!(file_view[0][1].data_type_raw(), 16);
+    }
+
+    #[test]
+    fn file_view_iter_banks() {
+        let events = [
+            event_le(0, 0, 0, 0, 1, &bank_16_le(*b"BNK0", 1, &[0])),
+            event_le(0, 0, 1, 0, 1, &[]),
+            event_le(
+                0,
+                0,
+                2,
+                0,
+                1,
+                &[bank_16_le(*b"BNK1", 1, &[1]), bank_16_le(*b"BNK2", 1, &[2])].concat(),
+            ),
+        ]
+        .concat();
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        let names = file_view
+            .iter_banks()
+            .map(BankView::name)
+            .collect::<Vec<_>>();
+        assert_eq!(names, [*b"BNK0", *b"BNK1", *b"BNK2"]);
+        assert_eq!(file_view.iter_banks().next_back().unwrap().data(), [2]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn file_view_par_iter_banks() {
+        let events = [
+            event_le(0, 0, 0, 0, 1, &bank_16_le(*b"BNK0", 4, &[1, 0])),
+            event_le(0, 0, 1, 0, 1, &bank_16_le(*b"BNK0", 4, &[2, 0, 3, 0])),
+        ]
+        .concat();
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        let sum: u16 = file_view
+            .par_iter_banks()
+            .flat_map_iter(|bank_view| bank_view.iter_as::<u16>(Endianness::Little).unwrap())
+            .sum();
+        assert_eq!(sum, 6);
+    }
+
+    #[test]
+    fn extra_bytes_between_banks() {
+        let banks = [
+            bank_16_le(*b"BNK0", 1, &[1]),
+            vec![0; 4],
+            bank_16_le(*b"BNK1", 1, &[2]),
+        ]
+        .concat();
+        let events = event_le(0, 0, 0, 0, 1, &banks);
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        assert!(FileView::try_from_bytes(&file).is_err());
+
+        let mut events = try_events(&file);
+        let error = events.next().unwrap().unwrap_err();
+        assert_eq!(error.event_index(), Some(0));
+    }
+
+    #[test]
+    fn file_view_find_serial_gaps() {
+        let serial_numbers = [5, 6, 8, 8, u32::MAX, 0, 1, 0];
+        let events = serial_numbers
+            .iter()
+            .flat_map(|&n| event_le(0, 0, n, 0, 1, &[]))
+            .collect::<Vec<_>>();
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        assert_eq!(
+            file_view.find_serial_gaps(),
+            [(6, 8), (8, 8), (8, u32::MAX), (1, 0)]
+        );
+
+        let file = file_le(0, 0, b"", &event_le(0, 0, 7, 0, 1, &[]), 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+        assert!(file_view.find_serial_gaps().is_empty());
+    }
+
+    #[test]
+    fn exact_size_iterators() {
+        fn assert_exact_size<I: ExactSizeIterator + DoubleEndedIterator>(iter: I) -> usize {
+            iter.len()
+        }
+
+        let banks = [bank_16_le(*b"BNK0", 1, &[]), bank_16_le(*b"BNK1", 1, &[])].concat();
+        let events = [
+            event_le(0, 0, 0, 0, 1, &banks),
+            event_le(0, 0, 1, 0, 1, &[]),
+        ]
+        .concat();
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        assert_eq!(assert_exact_size(file_view.iter()), 2);
+        assert_eq!(assert_exact_size(file_view.iter_rev()), 2);
+        assert_eq!(assert_exact_size(file_view.indexed()), 2);
+        assert_eq!(assert_exact_size(file_view[0].iter()), 2);
+        assert_eq!(assert_exact_size(file_view[0].clone().into_iter()), 2);
+    }
+
+    #[test]
+    fn expected_file_len_matches() {
+        let events = [
+            event_le(0, 0, 0, 0, 1, &bank_16_le(*b"BANK", 1, &[1, 2, 3])),
+            event_le(0, 0, 1, 0, 49, &bank_32a_le(*b"BANK", 1, &[1; 9])),
+        ]
+        .concat();
+        let file = file_le(0, 0, b"initial", &events, 0, b"final");
+        assert_eq!(expected_file_len(&file).unwrap(), file.len());
+
+        // Truncated final ODB dump, and trailing bytes.
+        assert_eq!(
+            expected_file_len(&file[..file.len() - 2]).unwrap(),
+            file.len()
+        );
+        let mut long = file.clone();
+        long.extend([0; 3]);
+        assert_eq!(expected_file_len(&long).unwrap(), file.len());
+
+        let file = file_be(0, 0, b"", &event_be(0, 0, 0, 0, 1, &[]), 0, b"odb");
+        assert_eq!(expected_file_len(&file).unwrap(), file.len());
+    }
+
+    #[test]
+    fn expected_file_len_truncated() {
+        let events = event_le(0, 0, 0, 0, 1, &bank_16_le(*b"BANK", 1, &[1, 2, 3]));
+        let file = file_le(0, 0, b"initial", &events, 0, b"final");
+
+        for len in [0, 10, 16 + 7 + 10, 16 + 7 + events.len(), file.len() - 8] {
+            assert!(expected_file_len(&file[..len]).is_err(), "{len}");
+        }
+        // Within the initial ODB dump or an event, the end of either is a lower
+        // bound of the length.
+        assert_eq!(expected_file_len(&file[..16 + 3]).unwrap(), 16 + 7);
+        for len in [16 + 7 + 16, 16 + 7 + events.len() - 1] {
+            assert_eq!(
+                expected_file_len(&file[..len]).unwrap(),
+                16 + 7 + events.len(),
+                "{len}"
+            );
+        }
+    }
+
+    #[test]
+    fn file_endianness_unchecked_le_and_be() {
+        let file = file_le(0, 0, b"", &[], 0, b"");
+        assert_eq!(
+            file_endianness_unchecked(&file).unwrap(),
+            Endianness::Little
+        );
+        let file = file_be(0, 0, b"", &[], 0, b"");
+        assert_eq!(file_endianness_unchecked(&file).unwrap(), Endianness::Big);
+        assert_eq!(
+            file_endianness_unchecked(b"\x00\x80").unwrap(),
+            Endianness::Little
+        );
+
+        assert!(file_endianness_unchecked(b"\x00").is_err());
+        assert!(file_endianness_unchecked(b"\x01\x80").is_err());
+    }
+
+    #[test]
+    fn bank_view_name_str() {
+        let banks = [
+            bank_16_le(*b"ADC0", 1, &[]),
+            bank_16_le(*b"AD\xFF0", 1, &[]),
+        ]
+        .concat();
+        let events = event_le(0, 0, 0, 0, 1, &banks);
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        let bank_view = &file_view[0][0];
+        assert_eq!(bank_view.name_str(), Ok("ADC0"));
+        assert_eq!(bank_view.name_str_lossy(), "ADC0");
+
+        let bank_view = &file_view[0][1];
+        assert!(bank_view.name_str().is_err());
+        assert_eq!(bank_view.name_str_lossy(), "AD\u{FFFD}0");
+    }
+
+    #[test]
+    fn try_lazy_events_matches_try_events() {
+        let events = [
+            event_le(
+                1,
+                0,
+                0,
+                0,
+                17,
+                &[
+                    bank_32_le(*b"ADC0", 1, &[1, 2, 3]),
+                    bank_32_le(*b"ADC1", 4, &[4; 4]),
+                ]
+                .concat(),
+            ),
+            event_le(2, 0, 1, 0, 49, &bank_32a_le(*b"TDC0", 1, &[5; 9])),
+            event_le(3, 0, 2, 0, 1, &[]),
+        ]
+        .concat();
+        let file = file_le(0, 0, b"odb", &events, 0, b"odb");
+
+        let event_views = try_events(&file).collect::<Result<Vec<_>, _>>().unwrap();
+        let lazy_views = try_lazy_events(&file)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(lazy_views.len(), event_views.len());
+        for (lazy, eager) in lazy_views.iter().zip(&event_views) {
+            assert_eq!(lazy.id(), eager.id());
+            assert_eq!(lazy.serial_number(), eager.serial_number());
+            assert_eq!(lazy.byte_offset(), eager.byte_offset());
+            assert_eq!(lazy.size_bytes(), eager.size_bytes());
+            assert_eq!(lazy.all_banks_bytes(), eager.all_banks_bytes());
+            assert!(lazy.banks().eq(eager.iter().copied()));
+            assert_eq!(lazy.to_event_view().banks(), eager.banks());
+        }
+        assert_eq!(lazy_views[0].bank(b"ADC1").unwrap().data(), &[4; 4]);
+        assert!(lazy_views[2].bank(b"ADC0").is_none());
+    }
+
+    #[test]
+    fn try_lazy_events_invalid_event() {
+        let good = event_le(0, 0, 0, 0, 1, &bank_16_le(*b"ADC0", 1, &[1]));
+        let bad = event_le(0, 0, 1, 0, 1, &bank_16_le(*b"ADC1", 99, &[1]));
+        let events = [&good[..], &bad, &good].concat();
+        let file = file_le(0, 0, b"", &events, 0, b"");
+
+        let eager = try_events(&file).nth(1).unwrap().unwrap_err();
+        let mut iter = try_lazy_events(&file);
+        assert!(iter.next().unwrap().is_ok());
+        let error = iter.next().unwrap().unwrap_err();
+        assert_eq!(error.offset(), eager.offset());
+        assert_eq!(error.event_index(), eager.event_index());
+        assert_eq!(error.bank_name(), eager.bank_name());
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn file_view_events_in_time_range() {
+        let events = [1, 3, 3, 5, 8]
+            .iter()
+            .enumerate()
+            .flat_map(|(i, &timestamp)| event_le(i as u16, 0, 0, timestamp, 1, &[]))
+            .collect::<Vec<_>>();
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        let ids = |iter: &mut dyn Iterator<Item = &EventView>| -> Vec<u16> {
+            iter.map(EventView::id).collect()
+        };
+        for (start, end, expected) in [
+            (3, 5, vec![1, 2, 3]),
+            (0, 1, vec![0]),
+            (4, 4, vec![]),
+            (6, u32::MAX, vec![4]),
+            (0, u32::MAX, vec![0, 1, 2, 3, 4]),
+            (5, 3, vec![]),
+        ] {
+            assert_eq!(
+                ids(&mut file_view.events_in_time_range(start, end)),
+                expected
+            );
+            assert_eq!(
+                ids(&mut file_view.events_in_time_range_sorted(start, end)),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn bank_view_new() {
+        let bank_view = BankView::new(*b"ADC0", DataType::I16, &[1, 0, 0xFF, 0xFF]).unwrap();
+        assert_eq!(bank_view.name(), *b"ADC0");
+        assert_eq!(bank_view.data_type(), DataType::I16);
+        assert_eq!(bank_view.data_type_raw(), 5);
+        assert_eq!(bank_view.endianness(), Endianness::Little);
+        assert!(bank_view
+            .values_i16(Endianness::Little)
+            .unwrap()
+            .eq([1, -1]));
+
+        let file = file_le(
+            0,
+            0,
+            b"",
+            &event_le(0, 0, 0, 0, 1, &bank_16_le(*b"ADC0", 5, &[1, 0, 0xFF, 0xFF])),
+            0,
+            b"",
+        );
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+        assert_eq!(file_view.iter_banks().next(), Some(&bank_view));
+
+        assert!(BankView::new(*b"STR0", DataType::Str, b"abc").is_ok());
+        assert_eq!(
+            BankView::new(*b"ADC0", DataType::U32, &[0; 6]),
+            Err(write::WriteError::InvalidBankData {
+                name: *b"ADC0",
+                data_type: DataType::U32,
+                len: 6,
+            })
+        );
+    }
+
+    #[test]
+    fn for_each_event_visits_all_events() {
+        let events = (0..3)
+            .flat_map(|i| event_le(i, 0, 0, 0, 1, &[]))
+            .collect::<Vec<_>>();
+        let file = file_le(0, 0, b"", &events, 0, b"");
+
+        let mut ids = Vec::new();
+        for_each_event(&file, |event_view| {
+            ids.push(event_view.id());
+            core::ops::ControlFlow::Continue(())
+        })
+        .unwrap();
+        assert_eq!(ids, [0, 1, 2]);
+    }
+
+    #[test]
+    fn for_each_event_break() {
+        let events = (0..3)
+            .flat_map(|i| event_le(i, 0, 0, 0, 1, &[]))
+            .collect::<Vec<_>>();
+        // The final ODB dump is missing, but parsing stops before it.
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file = &file[..file.len() - ODB_HEADER_LENGTH];
+
+        let mut ids = Vec::new();
+        for_each_event(file, |event_view| {
+            ids.push(event_view.id());
+            if event_view.id() == 1 {
+                core::ops::ControlFlow::Break(())
+            } else {
+                core::ops::ControlFlow::Continue(())
+            }
+        })
+        .unwrap();
+        assert_eq!(ids, [0, 1]);
+
+        let mut count = 0;
+        let error = for_each_event(file, |_| {
+            count += 1;
+            core::ops::ControlFlow::Continue(())
+        })
+        .unwrap_err();
+        assert_eq!(count, 3);
+        assert_eq!(error.offset(), file.len());
+    }
+
+    #[test]
+    fn parse_error_inconsistent_endianness() {
+        let good = event_le(0, 0, 0, 0, 1, &[]);
+        let mut swapped = event_le(0, 0, 1, 0, 1, &[]);
+        swapped[20..24].copy_from_slice(&1u32.to_be_bytes());
+        let mut invalid = event_le(0, 0, 1, 0, 1, &[]);
+        invalid[20..24].copy_from_slice(&2u32.to_le_bytes());
+
+        for (event, kind) in [
+            (swapped, ParseErrorKind::EndiannessInconsistent),
+            (invalid, ParseErrorKind::UnknownFlags),
+        ] {
+            let file = file_le(0, 0, b"", &[&good[..], &event].concat(), 0, b"");
+            let error = try_events(&file).nth(1).unwrap().unwrap_err();
+            assert_eq!(error.event_index(), Some(1));
+            assert_eq!(error.kind(), kind);
+        }
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn bank_view_cast_slice() {
+        // Force a correct alignment of the data for any `T`.
+        let data = [1u64.to_le(), 2u64.to_le()];
+        let data: &[u8] = bytemuck::cast_slice(&data);
+        let bank_view = BankView::new(*b"ADC0", DataType::U32, data).unwrap();
+
+        if Endianness::native() == Endianness::Little {
+            assert_eq!(bank_view.cast_slice::<u32>(), Some(&[1, 0, 2, 0][..]));
+            assert_eq!(bank_view.cast_slice::<i32>(), Some(&[1, 0, 2, 0][..]));
+            assert_eq!(
+                BankView::new(*b"ADC0", DataType::U32, &data[1..13])
+                    .unwrap()
+                    .cast_slice::<u32>(),
+                None
+            );
+        } else {
+            assert_eq!(bank_view.cast_slice::<u32>(), None);
+        }
+        assert_eq!(bank_view.cast_slice::<u16>(), None);
+        assert_eq!(bank_view.cast_slice::<u64>(), None);
+        assert_eq!(
+            BankView::new(*b"STR0", DataType::Str, data)
+                .unwrap()
+                .cast_slice::<u8>(),
+            None
+        );
+    }
+
+    #[test]
+    fn file_view_try_from_slice() {
+        let file = file_le(1, 2, b"", &event_le(0, 0, 0, 0, 1, &[]), 3, b"");
+
+        let file_view: FileView = file[..].try_into().unwrap();
+        assert_eq!(file_view.run_number(), 1);
+        assert_eq!(file_view.len(), 1);
+        assert!(FileView::try_from(&file[..file.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn event_view_flags() {
+        let events = [
+            event_le(0, 0, 0, 0, 1, &bank_16_le(*b"ADC0", 1, &[1])),
+            event_le(1, 0, 1, 0, 17, &bank_32_le(*b"ADC0", 1, &[1])),
+            event_le(2, 0, 2, 0, 49, &bank_32a_le(*b"ADC0", 1, &[1])),
+        ]
+        .concat();
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        let flags = file_view.iter().map(EventView::flags).collect::<Vec<_>>();
+        assert_eq!(flags, [1, 17, 49]);
+        let lazy_flags = try_lazy_events(&file)
+            .map(|event_view| event_view.unwrap().flags())
+            .collect::<Vec<_>>();
+        assert_eq!(lazy_flags, flags);
+    }
+
+    #[test]
+    fn bank_type_try_from_flags() {
+        for (flags, bank_type) in [
+            (1, BankType::B16),
+            (17, BankType::B32),
+            (49, BankType::B32A),
+        ] {
+            assert_eq!(BankType::try_from(flags), Ok(bank_type));
+            assert_eq!(bank_type.to_flag(), flags);
+        }
+        for flags in [0, 2, 16, 18, 33, 48, 50, u32::MAX, 1u32.swap_bytes()] {
+            assert!(BankType::try_from(flags).is_err());
+        }
+    }
+
+    #[test]
+    fn bank_type_of_event_and_bank_views() {
+        let events = [
+            event_le(0, 0, 0, 0, 1, &bank_16_le(*b"ADC0", 1, &[1])),
+            event_le(1, 0, 1, 0, 17, &bank_32_le(*b"ADC0", 1, &[1])),
+            event_le(2, 0, 2, 0, 49, &bank_32a_le(*b"ADC0", 1, &[1])),
+        ]
+        .concat();
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        for (event_view, bank_type) in
+            file_view
+                .iter()
+                .zip([BankType::B16, BankType::B32, BankType::B32A])
+        {
+            assert_eq!(event_view.bank_type(), bank_type);
+            assert_eq!(event_view[0].bank_type(), bank_type);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn file_try_from_parts() {
+        let events = (0..4)
+            .flat_map(|i| event_le(i, 0, i.into(), 0, 1, &bank_16_le(*b"ADC0", 1, &[1])))
+            .collect::<Vec<_>>();
+        let file = file_le(1, 0, b"odb", &events, 0, b"odb");
+        let event_len = events.len() / 4;
+        let first_end = 16 + 3 + event_len;
+        let second_end = first_end + 2 * event_len;
+        let parts = [
+            &file[..first_end],
+            &file[first_end..second_end],
+            &file[second_end..],
+        ];
+
+        let joined = File::try_from_parts(&parts).unwrap();
+        assert_eq!(&joined[..], &file[..]);
+        let file_view = joined.view();
+        assert_eq!(file_view.len(), 4);
+        assert_eq!(file_view.run_number(), 1);
+
+        let error = File::try_from_parts(&parts[..2]).unwrap_err();
+        assert_eq!(error.offset(), second_end);
+        assert!(File::try_from_parts(&parts[1..]).is_err());
+        assert!(File::try_from_parts(&[parts[0], parts[2], parts[1]]).is_err());
+        assert!(File::try_from_parts(&[]).is_err());
+    }
+
+    #[test]
+    fn event_view_bank_offsets() {
+        for (flags, bank) in [
+            (1, bank_16_le as fn([u8; 4], u16, &[u8]) -> Vec<u8>),
+            (17, |name, data_type, data| {
+                bank_32_le(name, data_type.into(), data)
+            }),
+            (49, |name, data_type, data| {
+                bank_32a_le(name, data_type.into(), data)
+            }),
+        ] {
+            let banks = [
+                bank(*b"ADC0", 1, &[1; 3]),
+                bank(*b"ADC1", 1, &[]),
+                bank(*b"ADC2", 1, &[2; 9]),
+            ]
+            .concat();
+            let events = [
+                event_le(0, 0, 0, 0, 1, &[]),
+                event_le(1, 0, 1, 0, flags, &banks),
+            ]
+            .concat();
+            let file = file_le(0, 0, b"odb", &events, 0, b"");
+            let file_view = FileView::try_from_bytes(&file).unwrap();
+
+            let event_view = &file_view[1];
+            let offsets = event_view.bank_offsets().collect::<Vec<_>>();
+            assert_eq!(offsets.len(), 3);
+            for (bank_view, offset) in offsets {
+                let start = event_view.byte_offset() + offset;
+                assert_eq!(&file[start..start + 4], bank_view.name());
+            }
+        }
+    }
+
+    #[test]
+    fn file_view_max_event_size() {
+        let events = [
+            event_le(0, 0, 0, 0, 1, &bank_16_le(*b"ADC0", 1, &[1; 8])),
+            event_le(1, 0, 1, 0, 1, &bank_16_le(*b"ADC0", 1, &[1; 16])),
+        ]
+        .concat();
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        // Event sizes are 24 and 32 bytes.
+        let options = FileViewOptions::new().max_event_size(31);
+        assert!(FileView::try_from_bytes_with(&file, options.max_event_size(32)).is_ok());
+
+        let error = FileView::try_from_bytes_with(&file, options).unwrap_err();
+        assert_eq!(error.offset(), 16 + 40 + 12);
+        assert_eq!(error.event_index(), Some(1));
+        assert!(error.to_string().contains("maximum event size"));
+        // Without limits, a truncated event is still a regular error.
+        assert!(!FileView::try_from_bytes(&file[..16 + 40 + 14])
+            .unwrap_err()
+            .to_string()
+            .contains("maximum"));
+    }
+
+    #[test]
+    fn file_view_max_bank_data_size() {
+        for (flags, bank) in [
+            (1, bank_16_le(*b"ADC1", 1, &[1; 9])),
+            (17, bank_32_le(*b"ADC1", 1, &[1; 9])),
+            (49, bank_32a_le(*b"ADC1", 1, &[1; 9])),
+        ] {
+            let small = match flags {
+                1 => bank_16_le(*b"ADC0", 1, &[1; 8]),
+                17 => bank_32_le(*b"ADC0", 1, &[1; 8]),
+                _ => bank_32a_le(*b"ADC0", 1, &[1; 8]),
+            };
+            let banks = [small.clone(), bank].concat();
+            let file = file_le(0, 0, b"", &event_le(0, 0, 0, 0, flags, &banks), 0, b"");
+            let options = FileViewOptions::new().max_bank_data_size(8);
+            assert!(FileView::try_from_bytes_with(&file, options.max_bank_data_size(9)).is_ok());
+
+            let error = FileView::try_from_bytes_with(&file, options).unwrap_err();
+            assert!(error.offset() > 16 + 24 + small.len());
+            assert_eq!(error.event_index(), Some(0));
+            assert_eq!(error.bank_name(), Some(*b"ADC1"));
+            assert_eq!(error.kind(), ParseErrorKind::SizeExceeded);
+            assert!(error.to_string().contains("maximum bank data size"));
+        }
+    }
+
+    #[test]
+    fn file_view_final_run_number() {
+        let mut file = file_le(1, 0, b"", &event_le(0, 0, 0, 0, 1, &[]), 0, b"");
+        assert_eq!(
+            FileView::try_from_bytes(&file).unwrap().final_run_number(),
+            1
+        );
+
+        let final_header = file.len() - ODB_HEADER_LENGTH;
+        file[final_header + 4..final_header + 8].copy_from_slice(&2u32.to_le_bytes());
+        assert!(FileView::try_from_bytes(&file).is_err());
+        let options = FileViewOptions::new().require_run_number_match(false);
+        let file_view = FileView::try_from_bytes_with(&file, options).unwrap();
+        assert_eq!(file_view.run_number(), 1);
+        assert_eq!(file_view.final_run_number(), 2);
+
+        let options = options.require_final_odb(false);
+        let file_view = FileView::try_from_bytes_with(&file[..final_header], options).unwrap();
+        assert_eq!(file_view.final_run_number(), 0);
+        let (file_view, _) = FileView::try_from_bytes_lenient(&file[..final_header]).unwrap();
+        assert_eq!(file_view.final_run_number(), 0);
+
+        let (file_view, recovery) = FileView::try_from_bytes_lenient(&file).unwrap();
+        assert!(recovery.is_none());
+        assert!(file_view.has_final_odb());
+        assert_eq!(file_view.run_number(), 1);
+        assert_eq!(file_view.final_run_number(), 2);
+        assert_eq!(file_view.iter().len(), 1);
+    }
+
+    #[test]
+    fn file_view_group_by_id() {
+        let events = [1, 1, 2, 1, 3, 3, 3]
+            .iter()
+            .enumerate()
+            .flat_map(|(i, &id)| event_le(id, 0, i as u32, 0, 1, &[]))
+            .collect::<Vec<_>>();
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        let groups = file_view
+            .group_by_id()
+            .map(|(id, event_views)| {
+                let serial_numbers = event_views
+                    .iter()
+                    .map(EventView::serial_number)
+                    .collect::<Vec<_>>();
+                (id, serial_numbers)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            groups,
+            [
+                (1, vec![0, 1]),
+                (2, vec![2]),
+                (1, vec![3]),
+                (3, vec![4, 5, 6])
+            ]
+        );
+
+        let file = file_le(0, 0, b"", &[], 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+        assert_eq!(file_view.group_by_id().count(), 0);
+    }
+
+    #[test]
+    fn bank_view_values_bool() {
+        let data = [0u32, 1, 0xFFFF_FFFF, 0, 0x100]
+            .iter()
+            .flat_map(|n| n.to_le_bytes())
+            .collect::<Vec<_>>();
+        let bank_view = BankView::new(*b"BOOL", DataType::Bool, &data).unwrap();
+        for endianness in [Endianness::Little, Endianness::Big] {
+            assert!(bank_view
+                .values_bool(endianness)
+                .unwrap()
+                .eq([false, true, true, false, true]));
+        }
+
+        let bank_view = BankView::new(*b"ADC0", DataType::U32, &data).unwrap();
+        assert!(bank_view.values_bool(Endianness::Little).is_none());
+        let bank_view = BankView::new(*b"BOOL", DataType::Bool, &[]).unwrap();
+        assert_eq!(
+            bank_view.values_bool(Endianness::Little).unwrap().count(),
+            0
+        );
+    }
+
+    #[test]
+    fn file_view_reverse_iterators() {
+        let events = (1..=3)
+            .flat_map(|serial_number| {
+                let banks = [bank_16_le(*b"BNK0", 1, &[]), bank_16_le(*b"BNK1", 1, &[])].concat();
+                event_le(0, 0, serial_number, 0, 1, &banks)
+            })
+            .collect::<Vec<_>>();
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        let serial_numbers = |iter: &mut dyn Iterator<Item = &EventView>| {
+            iter.map(EventView::serial_number).collect::<Vec<_>>()
+        };
+        assert_eq!(serial_numbers(&mut file_view.iter().rev()), [3, 2, 1]);
+        assert_eq!(
+            serial_numbers(&mut (&file_view).into_iter().rev()),
+            [3, 2, 1]
+        );
+        let names = file_view
+            .iter_banks()
+            .rev()
+            .take(3)
+            .map(BankView::name)
+            .collect::<Vec<_>>();
+        assert_eq!(names, [*b"BNK1", *b"BNK0", *b"BNK1"]);
+
+        let event_view = file_view.clone().into_iter().next_back().unwrap();
+        assert_eq!(event_view.serial_number(), 3);
+        let names = event_view
+            .into_iter()
+            .rev()
+            .map(|bank_view| bank_view.name())
+            .collect::<Vec<_>>();
+        assert_eq!(names, [*b"BNK1", *b"BNK0"]);
+        let serial_numbers = file_view
+            .into_iter()
+            .rev()
+            .map(|event_view| event_view.serial_number())
+            .collect::<Vec<_>>();
+        assert_eq!(serial_numbers, [3, 2, 1]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn file_view_summary() {
+        let events = [
+            event_le(
+                0,
+                0,
+                0,
+                5,
+                1,
+                &[
+                    bank_16_le(*b"ADC0", 4, &[1; 6]),
+                    bank_16_le(*b"ADC1", 4, &[1; 2]),
+                ]
+                .concat(),
+            ),
+            event_le(0, 0, 1, 6, 1, &[]),
+            event_le(0, 0, 2, 7, 1, &bank_16_le(*b"ADC0", 9, &[1; 4])),
+        ]
+        .concat();
+        let file = file_le(42, 1, b"odb", &events, 9, b"odb");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        assert_eq!(
+            file_view.summary(),
+            FileSummary {
+                run_number: 42,
+                initial_timestamp: 1,
+                final_timestamp: 9,
+                event_count: 3,
+                bank_count: 3,
+                bank_name_counts: [(*b"ADC0", 2), (*b"ADC1", 1)].into(),
+                data_types: [DataType::U16, DataType::F32].into(),
+                data_len: 12,
+            }
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn file_from_reader() {
+        let file = file_le(7, 0, b"odb", &event_le(0, 0, 0, 0, 1, &[]), 0, b"odb");
+
+        let owned = File::from_reader(&file[..]).unwrap();
+        assert_eq!(&owned[..], &file[..]);
+        assert_eq!(owned.view().run_number(), 7);
+
+        let result = File::from_reader(&file[..file.len() - 1]);
+        assert!(matches!(result, Err(ReadError::Parse(_))));
+    }
+
+    #[test]
+    fn data_type_from_rust_type_matches_size() {
+        fn check<T: MidasScalar>(data_type: DataType) {
+            assert_eq!(DataType::from_rust_type::<T>(), data_type);
+            assert_eq!(data_type.size(), Some(size_of::<T>()));
+        }
+        check::<u8>(DataType::U8);
+        check::<i8>(DataType::I8);
+        check::<u16>(DataType::U16);
+        check::<i16>(DataType::I16);
+        check::<u32>(DataType::U32);
+        check::<i32>(DataType::I32);
+        check::<f32>(DataType::F32);
+        check::<f64>(DataType::F64);
+        check::<i64>(DataType::I64);
+        check::<u64>(DataType::U64);
+    }
+
+    #[test]
+    fn file_view_events_with_bank_type() {
+        let events = [
+            event_le(0, 0, 0, 0, 1, &bank_16_le(*b"ADC0", 1, &[1])),
+            event_le(1, 0, 1, 0, 49, &bank_32a_le(*b"ADC0", 1, &[1])),
+            event_le(2, 0, 2, 0, 1, &[]),
+        ]
+        .concat();
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        let ids = |bank_type| {
+            file_view
+                .events_with_bank_type(bank_type)
+                .map(EventView::id)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ids(BankType::B16), [0, 2]);
+        assert_eq!(ids(BankType::B32), [] as [u16; 0]);
+        assert_eq!(ids(BankType::B32A), [1]);
+    }
+
+    #[test]
+    fn parse_error_kind() {
+        let event = event_le(0, 0, 0, 0, 1, &bank_16_le(*b"ADC0", 1, &[1]));
+        let file = file_le(1, 0, b"odb", &event, 0, b"odb");
+        let kind = |bytes: &[u8]| FileView::try_from_bytes(bytes).unwrap_err().kind();
+
+        assert_eq!(kind(&[]), ParseErrorKind::BadBorId);
+        assert_eq!(kind(&file[..1]), ParseErrorKind::BadBorId);
+        let mut bad = file.clone();
+        bad[2] = 0;
+        assert_eq!(kind(&bad), ParseErrorKind::BadMagic);
+        assert_eq!(kind(&file[..6]), ParseErrorKind::Truncated);
+        assert_eq!(kind(&file[..18]), ParseErrorKind::OdbSizeMismatch);
+        assert_eq!(
+            kind(&file[..file.len() - 1]),
+            ParseErrorKind::OdbSizeMismatch
+        );
+
+        let final_header = file.len() - ODB_HEADER_LENGTH - 3;
+        let mut bad = file.clone();
+        bad[final_header + 4] = 2;
+        assert_eq!(kind(&bad), ParseErrorKind::RunNumberMismatch);
+        // Without a valid magic marker, the final header is parsed as an event.
+        let mut bad = file.clone();
+        bad[final_header + 2] = 0;
+        assert_eq!(kind(&bad), ParseErrorKind::EventSizeMismatch);
+
+        let events = [
+            &event[..],
+            &event_le(0, 0, 1, 0, 1, &bank_16_le(*b"ADC1", 99, &[1])),
+        ]
+        .concat();
+        let file = file_le(1, 0, b"", &events, 0, b"");
+        assert_eq!(kind(&file), ParseErrorKind::InvalidBank);
+        let file = file_le(1, 0, b"", &event_le(0, 0, 0, 0, 2, &[]), 0, b"");
+        assert_eq!(kind(&file), ParseErrorKind::UnknownFlags);
+
+        let try_events_kind = |event: &[u8]| {
+            let file = file_le(1, 0, b"", event, 0, b"");
+            let kind = try_events(&file).next().unwrap().unwrap_err().kind();
+            kind
+        };
+        assert_eq!(
+            try_events_kind(&event_le(0, 0, 0, 0, 2, &[])),
+            ParseErrorKind::UnknownFlags
+        );
+        let mut bad = event.clone();
+        bad[12] += 1;
+        assert_eq!(try_events_kind(&bad), ParseErrorKind::EventSizeMismatch);
+
+        let options = FileViewOptions::new().max_event_size(8);
+        let file = file_le(1, 0, b"", &event, 0, b"");
+        let error = FileView::try_from_bytes_with(&file, options).unwrap_err();
+        assert_eq!(error.kind(), ParseErrorKind::SizeExceeded);
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn file_view_odb_json() {
+        let initial = b"{ \"/MIDAS version\" : \"2.1\", \"Runinfo\": { \"Run number\": 5 } }\n\0\0";
+        let file = file_le(5, 0, initial, &[], 0, b"[/System]\nKey = INT : 1\n");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        let json = file_view.initial_odb_json().unwrap();
+        assert_eq!(json["/MIDAS version"], "2.1");
+        assert_eq!(json["Runinfo"]["Run number"], 5);
+        assert!(file_view.final_odb_json().is_none());
+
+        let file = file_le(5, 0, b"", &[], 0, b"{ \"truncated\": ");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+        assert!(file_view.initial_odb_json().is_none());
+        assert!(file_view.final_odb_json().is_none());
+    }
+
+    #[test]
+    fn odb_parse_ascii_odb() {
+        use crate::odb::ascii::{parse_ascii_odb, OdbError};
+
+        let odb = b"[/Runinfo]\r\n\
+State = INT : 3\r\n\
+Run number = INT : 42\r\n\
+\r\n\
+[/Equipment/Trigger/Common]\r\n\
+Frontend name = STRING : [32] fetrigger\r\n\
+Comment = STRING : [64] first line\r\n\
+second line\r\n\
+Thresholds = WORD[2] :\r\n\
+[0] 100\r\n\
+[1] 200\r\n\
+Names = STRING[2] :\r\n\
+[16] a b\r\n\
+[16] \r\n\
+\0\0\0";
+        let tree = parse_ascii_odb(odb).unwrap();
+
+        let paths: Vec<_> = tree.sections().map(|(path, _)| path).collect();
+        assert_eq!(paths, ["/Equipment/Trigger/Common", "/Runinfo"]);
+        assert_eq!(tree.section("/Runinfo").unwrap().len(), 2);
+
+        let key = tree.get("/Runinfo", "Run number").unwrap();
+        assert_eq!(key.type_name(), "INT");
+        assert_eq!(key.value(), Some("42"));
+        assert!(!key.is_array());
+
+        let section = "/Equipment/Trigger/Common";
+        let key = tree.get(section, "Frontend name").unwrap();
+        assert_eq!(key.type_name(), "STRING");
+        assert_eq!(key.value(), Some("fetrigger"));
+        let key = tree.get(section, "Comment").unwrap();
+        assert_eq!(key.value(), Some("first line\nsecond line"));
+        let key = tree.get(section, "Thresholds").unwrap();
+        assert_eq!(key.type_name(), "WORD");
+        assert!(key.is_array());
+        assert_eq!(key.value(), None);
+        assert_eq!(key.values(), ["100", "200"]);
+        let key = tree.get(section, "Names").unwrap();
+        assert_eq!(key.values(), ["a b", ""]);
+        assert!(tree.get(section, "Missing").is_none());
+
+        assert_eq!(parse_ascii_odb(b"").unwrap().sections().count(), 0);
+        assert_eq!(
+            parse_ascii_odb(b"Key = INT : 1\n"),
+            Err(OdbError::KeyOutsideSection { line: 1 })
+        );
+        assert_eq!(
+            parse_ascii_odb(b"[/A]\nKey = INT : 1\nnot a key\n"),
+            Err(OdbError::InvalidLine { line: 3 })
+        );
+        assert_eq!(
+            parse_ascii_odb(b"[/A]\nKey = INT[2] :\n[0] 1\n"),
+            Err(OdbError::UnexpectedEnd)
+        );
+        assert_eq!(
+            parse_ascii_odb(b"[/A]\nX = INT[18446744073709551615] :\n[0] 1\n"),
+            Err(OdbError::UnexpectedEnd)
+        );
+        assert_eq!(
+            parse_ascii_odb(b"[/A]\nX = INT[18446744073709551616] :\n"),
+            Err(OdbError::InvalidLine { line: 2 })
+        );
+        assert_eq!(
+            parse_ascii_odb(b"[/A]\nX = INT[0] :\n"),
+            Err(OdbError::InvalidLine { line: 2 })
+        );
+        assert_eq!(
+            parse_ascii_odb(b"[/A]\nX = INT[1] :\n[/B]\nY = INT : 1\n"),
+            Err(OdbError::InvalidLine { line: 3 })
+        );
+        assert_eq!(
+            parse_ascii_odb(b"[/A]\nX = INT[2] :\n[0] 1\n[0] 2\n"),
+            Err(OdbError::InvalidLine { line: 4 })
+        );
+        assert_eq!(
+            parse_ascii_odb(b"[/A]\nX = INT[1] :\n[a] 1\n"),
+            Err(OdbError::InvalidLine { line: 3 })
+        );
+    }
+
+    #[test]
+    fn event_view_banks_with_padding() {
+        let mut bank_1 = bank_16_le(*b"BANK", 1, &[1, 2, 3]);
+        bank_1[11..].copy_from_slice(&[9, 8, 7, 6, 5]);
+        let bank_2 = bank_16_le(*b"BANK", 1, &[1; 8]);
+        let event = event_le(0, 0, 0, 0, 1, &[&bank_1[..], &bank_2].concat());
+        let file = file_le(0, 0, b"", &event, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        let banks: Vec<_> = file_view[0].banks_with_padding().collect();
+        assert_eq!(banks.len(), 2);
+        assert_eq!(banks[0].0.data(), [1, 2, 3]);
+        assert_eq!(banks[0].1, [9, 8, 7, 6, 5]);
+        assert_eq!(banks[1].1, [] as [u8; 0]);
+
+        let bytes: Vec<u8> = file_view[0]
+            .bank_offsets()
+            .zip(file_view[0].banks_with_padding())
+            .flat_map(|((_, offset), (bank_view, padding))| {
+                let header = &event[offset..][..BANK_16_HEADER_LENGTH];
+                [header, bank_view.data(), padding].concat()
+            })
+            .collect();
+        assert_eq!(bytes, file_view[0].all_banks_bytes());
+    }
+
+    #[test]
+    fn transform_events() {
+        use crate::write::EventBuilder;
+
+        let events = [
+            event_le(1, 0, 0, 0, 1, &bank_16_le(*b"BANK", 1, &[1])),
+            event_le(2, 0, 1, 0, 1, &bank_16_le(*b"BANK", 1, &[2])),
+            event_le(
+                1,
+                0,
+                2,
+                0,
+                1,
+                &[bank_16_le(*b"BANK", 1, &[3]), bank_16_le(*b"TRG0", 1, &[4])].concat(),
+            ),
+        ]
+        .concat();
+        let file = file_le(7, 10, b"initial odb", &events, 20, b"final odb");
+
+        let output = transform(&file, |event_view| {
+            if event_view.id() != 1 {
+                return None;
+            }
+            if event_view.serial_number() == 0 {
+                return Some(event_view.to_owned_event());
+            }
+            let mut builder =
+                EventBuilder::new(event_view.id()).serial_number(event_view.serial_number());
+            for bank_view in event_view.iter().filter(|b| b.name() != *b"TRG0") {
+                builder = builder.bank(
+                    bank_view.name(),
+                    bank_view.data_type(),
+                    bank_view.data().to_vec(),
+                );
+            }
+            builder.build(event_view.endianness()).ok()
+        })
+        .unwrap();
+        let file_view = FileView::try_from_bytes(&output).unwrap();
+
+        assert_eq!(file_view.run_number(), 7);
+        assert_eq!(file_view.initial_timestamp(), 10);
+        assert_eq!(file_view.initial_odb(), b"initial odb");
+        assert_eq!(file_view.final_timestamp(), 20);
+        assert_eq!(file_view.final_odb(), b"final odb");
+        assert_eq!(file_view.iter().len(), 2);
+        assert_eq!(file_view[0].serial_number(), 0);
+        assert_eq!(file_view[1].serial_number(), 2);
+        assert_eq!(file_view[1].iter().len(), 1);
+        assert_eq!(file_view[1].iter().next().unwrap().data(), [3]);
+
+        assert_eq!(
+            transform(&file, |e| Some(e.to_owned_event())).unwrap(),
+            file
+        );
+        assert!(matches!(
+            transform(&file[1..], |e| Some(e.to_owned_event())),
+            Err(TransformError::Parse(_))
+        ));
+        let error = transform(&file, |e| {
+            EventBuilder::new(e.id())
+                .build(Endianness::Big)
+                .ok()
+                .filter(|_| e.serial_number() == 1)
+        })
+        .unwrap_err();
+        assert!(matches!(
+            error,
+            TransformError::EndiannessMismatch { event_index: 1 }
+        ));
+    }
+
+    #[test]
+    fn contains_bank() {
+        let events = [
+            event_le(0, 0, 0, 0, 1, &bank_16_le(*b"ADC0", 1, &[1])),
+            event_le(0, 0, 1, 0, 1, &bank_16_le(*b"TRGL", 1, &[2])),
+        ]
+        .concat();
+        let file = file_le(0, 0, b"", &events, 0, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        assert!(!file_view[0].contains_bank(b"TRGL"));
+        assert!(file_view[1].contains_bank(b"TRGL"));
+        let serials: Vec<_> = file_view
+            .iter()
+            .filter(|e| e.contains_bank(b"TRGL"))
+            .map(EventView::serial_number)
+            .collect();
+        assert_eq!(serials, [1]);
+
+        assert!(file_view.any_event_contains_bank(b"ADC0"));
+        assert!(file_view.any_event_contains_bank(b"TRGL"));
+        assert!(!file_view.any_event_contains_bank(b"NONE"));
+    }
+
+    #[test]
+    fn display_summary() {
+        let banks = [bank_16_le(*b"BANK", 1, &[1]), bank_16_le(*b"BANK", 1, &[2])].concat();
+        let event = event_le(1, 0xab, 42, 1700000000, 1, &banks);
+        let file = file_le(7, 10, b"", &event, 20, b"");
+        let file_view = FileView::try_from_bytes(&file).unwrap();
+
+        assert_eq!(
+            file_view[0].display_summary().to_string(),
+            "id=1 mask=0x00ab serial=42 ts=1700000000 banks=2"
+        );
+        assert_eq!(
+            file_view.display_summary().to_string(),
+            "run=7 ts=10 final_ts=20 events=1"
+        );
+    }
+
+    #[test]
+    fn event_size_smaller_than_event_header_is_an_error() {
+        for event_size in [0u32, 4, 7] {
+            let mut event = event_le(0, 0, 0, 0, 1, &[]);
+            event[12..16].copy_from_slice(&event_size.to_le_bytes());
+            event[16..20].copy_from_slice(&event_size.wrapping_sub(8).to_le_bytes());
+            let file = file_le(0, 0, b"", &event, 0, b"");
+
+            let errors = [
+                FileView::try_from_bytes(&file).unwrap_err(),
+                try_events(&file).find_map(Result::err).unwrap(),
+                try_lazy_events(&file).find_map(Result::err).unwrap(),
+                for_each_event(&file, |_| core::ops::ControlFlow::Continue(())).unwrap_err(),
+            ];
+            #[cfg(feature = "std")]
+            let errors = errors.into_iter().chain(
+                match EventReader::new(&file[..]).unwrap().find_map(Result::err) {
+                    Some(ReadError::Parse(error)) => Some(error),
+                    error => panic!("unexpected error: {error:?}"),
+                },
+            );
+            for error in errors {
+                assert_eq!(error.kind(), ParseErrorKind::EventSizeMismatch);
+                assert_eq!(error.event_index(), Some(0));
+                assert_eq!(error.bank_name(), None);
+            }
+        }
+    }
+
+    #[test]
+    fn banks_size_larger_than_event_is_an_error() {
+        let mut event = event_le(0, 0, 0, 0, 1, &bank_16_le(*b"BANK", 1, &[1]));
+        event[16..20].copy_from_slice(&u32::MAX.to_le_bytes());
+        let file = file_le(0, 0, b"", &event, 0, b"");
+
+        let error = try_events(&file).find_map(Result::err).unwrap();
+        assert_eq!(error.kind(), ParseErrorKind::EventSizeMismatch);
+        assert_eq!(error.bank_name(), None);
+    }
+
+    #[test]
+    fn expected_file_len_saturates() {
+        let mut file = file_le(0, 0, b"", &[], 0, b"");
+        let len = file.len();
+        file[len - 4..].copy_from_slice(&u32::MAX.to_le_bytes());
+
+        let expected = expected_file_len(&file).unwrap();
+        assert_eq!(expected, len.saturating_add(u32::MAX as usize));
+    }
+
+    #[cfg(feature = "lz4")]
+    #[test]
+    fn read_lz4_file_truncated_frame() {
+        use lz4_flex::frame::FrameEncoder;
+        use std::io::Write;
+
+        let event = event_le(1, 2, 3, 4, 1, &bank_16_le(*b"BANK", 1, &[0xFF; 3]));
+        let bytes = file_le(5, 6, b"initial", &event, 7, b"final");
+
+        let mut encoder = FrameEncoder::new(Vec::new());
+        encoder.write_all(&bytes).unwrap();
+        let compressed = encoder.finish().unwrap();
+        assert_eq!(
+            crate::lz4::maybe_decompress(compressed.clone()).unwrap(),
+            bytes
+        );
+
+        for len in [4, 6, compressed.len() - 4, compressed.len() - 1] {
+            let result = crate::lz4::maybe_decompress(compressed[..len].to_vec());
+            assert_eq!(
+                result.unwrap_err().kind(),
+                std::io::ErrorKind::UnexpectedEof,
+                "{len}"
+            );
+        }
+        // A complete frame followed by a truncated one.
+        let mut two_frames = compressed.clone();
+        two_frames.extend_from_slice(&compressed[..4]);
+        let result = crate::lz4::maybe_decompress(two_frames);
+        assert_eq!(
+            result.unwrap_err().kind(),
+            std::io::ErrorKind::UnexpectedEof
+        );
+    }
 }
@@ -0,0 +1,88 @@
+use lz4_flex::frame::FrameDecoder;
+use std::io::Read;
+use std::path::Path;
+
+const LZ4_MAGIC: [u8; 4] = [0x04, 0x22, 0x4D, 0x18];
+
+/// Reads the entire contents of a, possibly lz4-compressed, file into a bytes
+/// vector.
+///
+/// Lz4 compression is detected from the magic bytes at the beginning of the
+/// file (not from the file extension), so an uncompressed MIDAS file is
+/// returned as is. MIDAS can write a file as multiple concatenated lz4 frames;
+/// all of them are decompressed as a single stream.
+///
+/// A [`FileView`](crate::FileView) borrows from the returned buffer, so the
+/// buffer must outlive it.
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let contents = midasio::read_lz4_file("run00042.mid.lz4")?;
+/// let file_view = midasio::FileView::try_from_bytes(&contents)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn read_lz4_file<P: AsRef<Path>>(path: P) -> std::io::Result<Vec<u8>> {
+    maybe_decompress(std::fs::read(path)?)
+}
+
+// Decompress the contents of a file only if they start with the lz4 magic
+// bytes.
+pub(crate) fn maybe_decompress(contents: Vec<u8>) -> std::io::Result<Vec<u8>> {
+    if !contents.starts_with(&LZ4_MAGIC) {
+        return Ok(contents);
+    }
+
+    let mut decompressed = Vec::new();
+    let mut decoder = FrameDecoder::new(&contents[..]);
+    // The decoder stops at the end of each frame.
+    while !decoder.get_ref().is_empty() {
+        // The decoder treats running out of input at a block boundary as the
+        // end of the frame, so a truncated frame would be silently accepted.
+        let remaining = decoder.get_ref();
+        if remaining.starts_with(&LZ4_MAGIC) && frame_len(remaining).is_none() {
+            return Err(std::io::ErrorKind::UnexpectedEof.into());
+        }
+        decoder.read_to_end(&mut decompressed)?;
+    }
+    Ok(decompressed)
+}
+
+// Length in bytes of the lz4 frame at the beginning of `bytes` (including the
+// magic number). Returns `None` if the frame is incomplete.
+fn frame_len(bytes: &[u8]) -> Option<usize> {
+    const CONTENT_SIZE: u8 = 0x08;
+    const DICTIONARY_ID: u8 = 0x01;
+    const BLOCK_CHECKSUM: u8 = 0x10;
+    const CONTENT_CHECKSUM: u8 = 0x04;
+
+    let flags = *bytes.get(4)?;
+    // Magic number, FLG, BD, and HC bytes.
+    let mut len = 7;
+    if flags & CONTENT_SIZE != 0 {
+        len += 8;
+    }
+    if flags & DICTIONARY_ID != 0 {
+        len += 4;
+    }
+    loop {
+        let block_info = bytes.get(len..)?.first_chunk::<4>()?;
+        len += 4;
+        // The highest bit only flags an uncompressed block.
+        let block_size = u32::from_le_bytes(*block_info) & 0x7FFF_FFFF;
+        if block_size == 0 {
+            break;
+        }
+        len = len.checked_add(usize::try_from(block_size).ok()?)?;
+        if flags & BLOCK_CHECKSUM != 0 {
+            len += 4;
+        }
+    }
+    if flags & CONTENT_CHECKSUM != 0 {
+        len += 4;
+    }
+
+    (len <= bytes.len()).then_some(len)
+}
@@ -0,0 +1,72 @@
+use crate::{FileView, ParseError};
+use memmap2::Mmap;
+use std::path::Path;
+
+/// A memory-mapped MIDAS file.
+///
+/// A [`FileView`] borrows from the bytes it was created from, which makes it
+/// awkward to store next to the [`Mmap`] that owns them. An `MmapFile` owns the
+/// memory map instead, and [`MmapFile::view`] borrows from it.
+///
+/// An `MmapFile` dereferences to the raw bytes of the file.
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// // SAFETY: The file is not modified while it is mapped.
+/// let file = unsafe { midasio::MmapFile::open("example.mid")? };
+/// let file_view = file.view()?;
+///
+/// for event_view in &file_view {
+///     // Do something with each event in the file.
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct MmapFile {
+    mmap: Mmap,
+}
+
+impl MmapFile {
+    /// Opens and memory-maps the file at the given path.
+    ///
+    /// # Safety
+    ///
+    /// The underlying file must not be modified (by this or any other
+    /// process) while it is mapped; otherwise, the bytes behind any
+    /// [`FileView`] would change under its feet, which is undefined behavior.
+    /// See [`Mmap`] for details.
+    pub unsafe fn open<P: AsRef<Path>>(path: P) -> std::io::Result<Self> {
+        let file = std::fs::File::open(path)?;
+        let mmap = Mmap::map(&file)?;
+        Ok(MmapFile { mmap })
+    }
+    /// Returns a view to the memory-mapped file.
+    ///
+    /// The file is parsed every time this is called.
+    pub fn view(&self) -> Result<FileView<'_>, ParseError> {
+        FileView::try_from_bytes(&self.mmap)
+    }
+}
+
+impl From<Mmap> for MmapFile {
+    fn from(mmap: Mmap) -> Self {
+        MmapFile { mmap }
+    }
+}
+
+impl std::ops::Deref for MmapFile {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.mmap
+    }
+}
+
+impl AsRef<[u8]> for MmapFile {
+    fn as_ref(&self) -> &[u8] {
+        &self.mmap
+    }
+}
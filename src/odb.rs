@@ -0,0 +1,6 @@
+//! Utilities to read the ODB dumps stored at the beginning and end of a MIDAS
+//! file.
+//!
+//! See [`OdbFormat`](crate::OdbFormat) to find out the format of a dump.
+
+pub mod ascii;
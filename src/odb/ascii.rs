@@ -0,0 +1,269 @@
+//! Parser for the legacy ASCII ODB format.
+//!
+//! Older MIDAS files store their ODB dumps as plain text, one section per
+//! subdirectory of the ODB:
+//!
+//! ```text
+//! [/Runinfo]
+//! State = INT : 3
+//! Run number = INT : 42
+//!
+//! [/Equipment/Trigger/Common]
+//! Frontend name = STRING : [32] fetrigger
+//! Thresholds = WORD[2] :
+//! [0] 100
+//! [1] 200
+//! ```
+//!
+//! # Examples
+//!
+//! ```
+//! use midasio::odb::ascii::parse_ascii_odb;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let odb = parse_ascii_odb(b"[/Runinfo]\r\nRun number = INT : 42\r\n\0\0")?;
+//!
+//! let key = odb.get("/Runinfo", "Run number").unwrap();
+//! assert_eq!(key.type_name(), "INT");
+//! assert_eq!(key.value(), Some("42"));
+//! # Ok(())
+//! # }
+//! ```
+
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// The error type returned when parsing an ASCII ODB dump fails.
+///
+/// Line numbers start at 1.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum OdbError {
+    /// A key appears before the first `[/...]` section header.
+    KeyOutsideSection {
+        /// Line number of the key.
+        line: usize,
+    },
+    /// A line is neither a section header, a key, nor an array element.
+    InvalidLine {
+        /// Line number of the invalid line.
+        line: usize,
+    },
+    /// The dump ends before all the elements of an array are listed.
+    UnexpectedEnd,
+}
+
+impl core::fmt::Display for OdbError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            OdbError::KeyOutsideSection { line } => {
+                write!(f, "key at line `{line}` is not inside a section")
+            }
+            OdbError::InvalidLine { line } => write!(f, "invalid line `{line}`"),
+            OdbError::UnexpectedEnd => write!(f, "unexpected end of array"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OdbError {}
+
+/// A key in an ASCII ODB dump.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OdbKey {
+    type_name: String,
+    values: Vec<String>,
+    is_array: bool,
+}
+
+impl OdbKey {
+    /// Returns the MIDAS type name of the key (e.g. `INT`, `DOUBLE`, or
+    /// `STRING`).
+    pub fn type_name(&self) -> &str {
+        &self.type_name
+    }
+    /// Returns the value of the key, or [`None`] if the key is an array.
+    ///
+    /// The `[size]` prefix of strings is removed.
+    pub fn value(&self) -> Option<&str> {
+        if self.is_array {
+            None
+        } else {
+            self.values.first().map(String::as_str)
+        }
+    }
+    /// Returns all the values of the key, in order. A key that is not an array
+    /// has exactly one value.
+    ///
+    /// The `[index]` prefix of array elements is removed.
+    pub fn values(&self) -> &[String] {
+        &self.values
+    }
+    /// Returns `true` if the key was declared as an array (e.g. `INT[4]`).
+    pub fn is_array(&self) -> bool {
+        self.is_array
+    }
+}
+
+/// A parsed ASCII ODB dump.
+///
+/// Sections are identified by their full path (e.g. `/Equipment/Trigger`),
+/// and hold the keys listed under their `[path]` header. Sections are not
+/// nested; a key in a subdirectory belongs only to the section of that
+/// subdirectory.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct OdbTree {
+    sections: BTreeMap<String, BTreeMap<String, OdbKey>>,
+}
+
+impl OdbTree {
+    /// Returns the keys in the section with the given path, sorted by name.
+    pub fn section(&self, path: &str) -> Option<&BTreeMap<String, OdbKey>> {
+        self.sections.get(path)
+    }
+    /// Returns an iterator over all the sections, sorted by path.
+    pub fn sections(&self) -> impl Iterator<Item = (&str, &BTreeMap<String, OdbKey>)> {
+        self.sections
+            .iter()
+            .map(|(path, keys)| (path.as_str(), keys))
+    }
+    /// Returns the key with the given name in the section with the given path.
+    pub fn get(&self, path: &str, name: &str) -> Option<&OdbKey> {
+        self.section(path)?.get(name)
+    }
+}
+
+fn is_string_type(type_name: &str) -> bool {
+    matches!(type_name, "STRING" | "LINK")
+}
+
+// Splits a leading `[n] ` (string size or array index) from the rest of the
+// value.
+fn split_bracket(value: &str) -> Option<(usize, &str)> {
+    let (n, value) = value.strip_prefix('[')?.split_once(']')?;
+    Some((n.parse().ok()?, value.strip_prefix(' ').unwrap_or(value)))
+}
+
+fn section_path(line: &str) -> Option<&str> {
+    line.strip_prefix('[')
+        .and_then(|l| l.strip_suffix(']'))
+        .filter(|path| path.starts_with('/'))
+}
+
+/// Parses an ASCII ODB dump.
+///
+/// Trailing NUL bytes, a leading UTF-8 byte order mark, and both LF and CRLF
+/// line endings are accepted. Bytes that are not valid UTF-8 are replaced with
+/// [`U+FFFD`](char::REPLACEMENT_CHARACTER). Lines that follow a (non-array)
+/// string key and do not look like a key are appended to the value of that
+/// key, as MIDAS does with multi-line strings.
+///
+/// Values are not interpreted; they are kept as strings exactly as they appear
+/// in the dump (trimmed, except for strings).
+pub fn parse_ascii_odb(bytes: &[u8]) -> Result<OdbTree, OdbError> {
+    let bytes = bytes.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(bytes);
+    let len = bytes.iter().rposition(|&b| b != 0).map_or(0, |i| i + 1);
+    let text = String::from_utf8_lossy(&bytes[..len]);
+
+    let mut sections: BTreeMap<String, BTreeMap<String, OdbKey>> = BTreeMap::new();
+    let mut section: Option<String> = None;
+    // Last key in the current section, and how many array elements it is
+    // still missing.
+    let mut last: Option<String> = None;
+    let mut remaining = 0;
+
+    for (index, line) in text.lines().enumerate() {
+        let line_number = index + 1;
+        let line = line.strip_suffix('\r').unwrap_or(line);
+
+        let current = section
+            .as_ref()
+            .zip(last.as_ref())
+            .and_then(|(section, name)| sections.get_mut(section)?.get_mut(name));
+        if remaining > 0 {
+            let key = current.unwrap();
+            // Elements of string arrays are prefixed with the string size
+            // instead of their index.
+            let is_string = is_string_type(&key.type_name);
+            let value = split_bracket(line)
+                .filter(|_| section_path(line).is_none())
+                .filter(|&(n, _)| is_string || n == key.values.len())
+                .map(|(_, value)| if is_string { value } else { value.trim() })
+                .ok_or(OdbError::InvalidLine { line: line_number })?;
+            key.values.push(value.to_string());
+            remaining -= 1;
+            continue;
+        }
+        if line.trim().is_empty() {
+            continue;
+        }
+        if let Some(path) = section_path(line) {
+            sections.entry(path.to_string()).or_default();
+            section = Some(path.to_string());
+            last = None;
+            continue;
+        }
+
+        let Some((name, rest)) = line.split_once(" = ") else {
+            match current {
+                Some(key) if !key.is_array && is_string_type(&key.type_name) => {
+                    let value = key.values.last_mut().unwrap();
+                    value.push('\n');
+                    value.push_str(line);
+                    continue;
+                }
+                _ => return Err(OdbError::InvalidLine { line: line_number }),
+            }
+        };
+        let Some(keys) = section.as_ref().and_then(|path| sections.get_mut(path)) else {
+            return Err(OdbError::KeyOutsideSection { line: line_number });
+        };
+        let (type_spec, value) = rest
+            .split_once(':')
+            .ok_or(OdbError::InvalidLine { line: line_number })?;
+        let type_spec = type_spec.trim();
+        let (type_name, array_len) = match type_spec
+            .strip_suffix(']')
+            .and_then(|spec| spec.split_once('['))
+        {
+            Some((type_name, len)) => {
+                let len = len
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|&len| len > 0)
+                    .ok_or(OdbError::InvalidLine { line: line_number })?;
+                (type_name, Some(len))
+            }
+            None => (type_spec, None),
+        };
+        let values = match array_len {
+            Some(len) => {
+                remaining = len;
+                // The length comes from the dump, so it is not trusted for
+                // preallocation.
+                Vec::new()
+            }
+            None if is_string_type(type_name) => {
+                let value = value.strip_prefix(' ').unwrap_or(value);
+                let value = split_bracket(value).map_or(value, |(_, value)| value);
+                alloc::vec![value.to_string()]
+            }
+            None => alloc::vec![value.trim().to_string()],
+        };
+        keys.insert(
+            name.to_string(),
+            OdbKey {
+                type_name: type_name.to_string(),
+                values,
+                is_array: array_len.is_some(),
+            },
+        );
+        last = Some(name.to_string());
+    }
+
+    if remaining > 0 {
+        return Err(OdbError::UnexpectedEnd);
+    }
+    Ok(OdbTree { sections })
+}
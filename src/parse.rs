@@ -1,16 +1,23 @@
-use crate::{BankView, DataType, EventView, FileView};
-use std::mem::size_of;
-use winnow::binary::{le_u16, length_and_then, length_take, u16, u32, Endianness};
-use winnow::combinator::{dispatch, empty, eof, fail, repeat, repeat_till, seq, terminated};
-use winnow::error::{ContextError, PResult, StrContext};
+use crate::constants::{BANK_DATA_ALIGNMENT, BOR_ID, EOR_ID, MAGIC};
+use crate::{
+    BankType, BankView, DataType, Endianness, EventView, FileView, FileViewOptions, ParseError,
+    TryDataTypeFromUnsignedError,
+};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use winnow::binary::{be_u16, be_u32, le_u16, le_u32, length_and_then, length_take};
+use winnow::combinator::{
+    cut_err, dispatch, empty, eof, fail, not, opt, peek, preceded, repeat, seq, terminated,
+};
+use winnow::error::{ContextError, ModalResult, StrContext};
+use winnow::stream::{Accumulate, Offset};
 use winnow::token::take;
 use winnow::Parser;
 
 macro_rules! impl_data_type_from_unsigned {
     ($num_type:ty) => {
-        #[doc(hidden)]
         impl TryFrom<$num_type> for DataType {
-            type Error = ();
+            type Error = TryDataTypeFromUnsignedError;
 
             fn try_from(num: $num_type) -> Result<Self, Self::Error> {
                 match num {
@@ -28,11 +35,11 @@ macro_rules! impl_data_type_from_unsigned {
                     12 => Ok(DataType::Str),
                     13 => Ok(DataType::Array),
                     14 => Ok(DataType::Struct),
-                    15 => Ok(DataType::Str),
-                    16 => Ok(DataType::Str),
+                    15 => Ok(DataType::Key),
+                    16 => Ok(DataType::Link),
                     17 => Ok(DataType::I64),
                     18 => Ok(DataType::U64),
-                    _ => Err(()),
+                    _ => Err(TryDataTypeFromUnsignedError(())),
                 }
             }
         }
@@ -45,83 +52,255 @@ macro_rules! impl_data_type_from_unsigned {
 }
 impl_data_type_from_unsigned!(u16, u32);
 
-impl DataType {
-    fn size(&self) -> Option<usize> {
-        match self {
-            DataType::U8 => Some(size_of::<u8>()),
-            DataType::I8 => Some(size_of::<i8>()),
-            DataType::U16 => Some(size_of::<u16>()),
-            DataType::I16 => Some(size_of::<i16>()),
-            DataType::U32 => Some(size_of::<u32>()),
-            DataType::I32 => Some(size_of::<i32>()),
-            DataType::Bool => Some(4),
-            DataType::F32 => Some(size_of::<f32>()),
-            DataType::F64 => Some(size_of::<f64>()),
-            DataType::Str => None,
-            DataType::Array => None,
-            DataType::Struct => None,
-            DataType::I64 => Some(size_of::<i64>()),
-            DataType::U64 => Some(size_of::<u64>()),
+pub(crate) fn u16<'a>(endianness: Endianness) -> impl Parser<&'a [u8], u16, ContextError> {
+    move |input: &mut &'a [u8]| match endianness {
+        Endianness::Little => le_u16.parse_next(input),
+        Endianness::Big => be_u16.parse_next(input),
+    }
+}
+
+pub(crate) fn u32<'a>(endianness: Endianness) -> impl Parser<&'a [u8], u32, ContextError> {
+    move |input: &mut &'a [u8]| match endianness {
+        Endianness::Little => le_u32.parse_next(input),
+        Endianness::Big => be_u32.parse_next(input),
+    }
+}
+
+// Size declared in a header, which fails without backtracking if it is larger
+// than `max`. This reports the error at the size itself, instead of it being
+// masked by an enclosing `repeat`.
+fn size_at_most<'a>(
+    size: impl Parser<&'a [u8], u32, ContextError>,
+    max: u32,
+    label: &'static str,
+) -> impl Parser<&'a [u8], u32, ContextError> {
+    let mut size = size;
+    move |input: &mut &'a [u8]| {
+        if peek(size.by_ref()).parse_next(input)? > max {
+            return cut_err(fail.context(StrContext::Label(label))).parse_next(input);
         }
+        size.parse_next(input)
     }
 }
 
-fn bank_16_view<'a>(endianness: Endianness) -> impl Parser<&'a [u8], BankView<'a>, ContextError> {
+fn bank_16_view<'a>(
+    endianness: Endianness,
+    max_data_size: u32,
+) -> impl Parser<&'a [u8], BankView<'a>, ContextError> {
     seq! {BankView {
-        name: take(4usize).map(|b: &[u8]| b.try_into().unwrap()),
-        data_type: u16(endianness).verify_map(|n| DataType::try_from(n).ok()),
-        data : length_take::<&[u8], _, _, _>(u16(endianness))
-            .verify(|b: &[u8]| b.len() % data_type.size().unwrap_or(1) == 0),
-        _: take(data.len().next_multiple_of(8) - data.len()),
+        name: take(4usize).try_map(<[u8; 4]>::try_from),
+        data_type_raw: u16(endianness).map(u32::from),
+        data_type: empty.value(data_type_raw).try_map(DataType::try_from)
+            .context(StrContext::Label("bank data type")),
+        data : length_take::<&[u8], _, _, _>(
+            size_at_most(u16(endianness).map(u32::from), max_data_size, "maximum bank data size")
+        ),
+        _: empty.value(data).verify(|b: &[u8]| b.len().is_multiple_of(data_type.size().unwrap_or(1)))
+            .context(StrContext::Label("bank data")),
+        _: take(data.len().next_multiple_of(BANK_DATA_ALIGNMENT) - data.len()),
+        bank_type: empty.value(BankType::B16),
+        endianness: empty.value(endianness),
     }}
 }
 
-fn bank_32_view<'a>(endianness: Endianness) -> impl Parser<&'a [u8], BankView<'a>, ContextError> {
+fn bank_32_view<'a>(
+    endianness: Endianness,
+    max_data_size: u32,
+) -> impl Parser<&'a [u8], BankView<'a>, ContextError> {
     seq! {BankView {
-        name: take(4usize).map(|b: &[u8]| b.try_into().unwrap()),
-        data_type: u32(endianness).verify_map(|n| DataType::try_from(n).ok()),
-        data : length_take::<&[u8], _, _, _>(u32(endianness))
-            .verify(|b: &[u8]| b.len() % data_type.size().unwrap_or(1) == 0),
-        _: take(data.len().next_multiple_of(8) - data.len()),
+        name: take(4usize).try_map(<[u8; 4]>::try_from),
+        data_type_raw: u32(endianness),
+        data_type: empty.value(data_type_raw).try_map(DataType::try_from)
+            .context(StrContext::Label("bank data type")),
+        data : length_take::<&[u8], _, _, _>(
+            size_at_most(u32(endianness), max_data_size, "maximum bank data size")
+        ),
+        _: empty.value(data).verify(|b: &[u8]| b.len().is_multiple_of(data_type.size().unwrap_or(1)))
+            .context(StrContext::Label("bank data")),
+        _: take(data.len().next_multiple_of(BANK_DATA_ALIGNMENT) - data.len()),
+        bank_type: empty.value(BankType::B32),
+        endianness: empty.value(endianness),
     }}
 }
 
-fn bank_32a_view<'a>(endianness: Endianness) -> impl Parser<&'a [u8], BankView<'a>, ContextError> {
+fn bank_32a_view<'a>(
+    endianness: Endianness,
+    max_data_size: u32,
+) -> impl Parser<&'a [u8], BankView<'a>, ContextError> {
     seq! {BankView{
-        name: take(4usize).map(|b: &[u8]| b.try_into().unwrap()),
-        data_type: u32(endianness).verify_map(|n| DataType::try_from(n).ok()),
-        data: length_take::<&[u8], _, _, _>(terminated(u32(endianness), take(4usize)))
-            .verify(|b: &[u8]| b.len() % data_type.size().unwrap_or(1) == 0),
-        _: take(data.len().next_multiple_of(8) - data.len()),
+        name: take(4usize).try_map(<[u8; 4]>::try_from),
+        data_type_raw: u32(endianness),
+        data_type: empty.value(data_type_raw).try_map(DataType::try_from)
+            .context(StrContext::Label("bank data type")),
+        data: length_take::<&[u8], _, _, _>(terminated(
+            size_at_most(u32(endianness), max_data_size, "maximum bank data size"),
+            take(4usize),
+        )),
+        _: empty.value(data).verify(|b: &[u8]| b.len().is_multiple_of(data_type.size().unwrap_or(1)))
+            .context(StrContext::Label("bank data")),
+        _: take(data.len().next_multiple_of(BANK_DATA_ALIGNMENT) - data.len()),
+        bank_type: empty.value(BankType::B32A),
+        endianness: empty.value(endianness),
     }}
 }
 
-fn event_view<'a>(endianness: Endianness) -> impl Parser<&'a [u8], EventView<'a>, ContextError> {
-    seq! {EventView {
-        id: u16(endianness),
-        trigger_mask: u16(endianness),
-        serial_number: u32(endianness),
-        timestamp: u32(endianness),
-        bank_views: u32(endianness)
-            .verify(|&event_size| event_size >= 8)
-            .flat_map(|event_size| {
-                u32(endianness).verify(move |&banks_size| banks_size == event_size - 8)
-            })
-            .flat_map(|banks_size| {dispatch! {u32(endianness);
-                1 => length_and_then(empty.value(banks_size), repeat_till(0.., bank_16_view(endianness), eof)),
-                17 => length_and_then(empty.value(banks_size), repeat_till(0.., bank_32_view(endianness), eof)),
-                49 => length_and_then(empty.value(banks_size), repeat_till(0.., bank_32a_view(endianness), eof)),
-                _ => fail,
-            }}).map(|(bank_views, _): (Vec<_>, _)| bank_views.into_boxed_slice()),
-    }}
+// Data bank of an event with the given bank flags.
+pub(crate) fn bank_view<'a>(
+    endianness: Endianness,
+    flags: u32,
+    max_data_size: u32,
+) -> impl Parser<&'a [u8], BankView<'a>, ContextError> {
+    move |input: &mut &'a [u8]| match BankType::try_from(flags) {
+        Ok(BankType::B16) => bank_16_view(endianness, max_data_size).parse_next(input),
+        Ok(BankType::B32) => bank_32_view(endianness, max_data_size).parse_next(input),
+        Ok(BankType::B32A) => bank_32a_view(endianness, max_data_size).parse_next(input),
+        Err(_) => fail.parse_next(input),
+    }
+}
+
+// An event with its data banks accumulated into `C`.
+pub(crate) struct RawEvent<'a, C> {
+    pub(crate) id: u16,
+    pub(crate) trigger_mask: u16,
+    pub(crate) serial_number: u32,
+    pub(crate) timestamp: u32,
+    pub(crate) flags: u32,
+    pub(crate) bank_views: C,
+    // Raw bytes of the data banks, without the all-banks header.
+    pub(crate) banks: &'a [u8],
+    // Raw bytes of the entire event.
+    pub(crate) bytes: &'a [u8],
+}
+
+// The byte order of a file is determined from its begin-of-run ID alone. Bank
+// flags that are only valid after swapping their bytes indicate events written
+// with a different byte order than the rest of the file.
+fn flags_label(flags: u32) -> &'static str {
+    if BankType::try_from(flags.swap_bytes()).is_ok() {
+        "bank flags (byte order inconsistent with the begin-of-run id)"
+    } else {
+        "bank flags"
+    }
+}
+
+pub(crate) fn raw_event<'a, C: Accumulate<BankView<'a>>>(
+    endianness: Endianness,
+    options: FileViewOptions,
+) -> impl Parser<&'a [u8], RawEvent<'a, C>, ContextError> {
+    // The data banks (including their padding) have to add up to exactly the
+    // size of all banks. Otherwise, a crafted size could silently truncate the
+    // event or swallow the bytes of the next one. Any bytes left have to be a
+    // valid bank, so the error within it is reported without backtracking.
+    fn exact_banks<'a, C: Accumulate<BankView<'a>>>(
+        bank_view: impl Parser<&'a [u8], BankView<'a>, ContextError>,
+    ) -> impl Parser<&'a [u8], C, ContextError> {
+        repeat(
+            0..,
+            preceded(
+                not(eof),
+                cut_err(bank_view.context(StrContext::Label("size of all banks"))),
+            ),
+        )
+    }
+
+    let flags_and_bank_views = size_at_most(
+        u32(endianness),
+        options.max_event_size,
+        "maximum event size",
+    )
+    .verify(|&event_size| event_size >= 8)
+    .context(StrContext::Label("event size"))
+    .flat_map(move |event_size| {
+        u32(endianness)
+            .verify(move |&banks_size| event_size.checked_sub(8) == Some(banks_size))
+            .context(StrContext::Label("size of all banks"))
+    })
+    .flat_map(move |banks_size| {
+        u32(endianness).flat_map(move |flags| {
+            dispatch! {empty.value(BankType::try_from(flags));
+                Ok(_) => length_and_then(
+                    empty.value(banks_size),
+                    exact_banks(bank_view(endianness, flags, options.max_bank_data_size))
+                        .with_taken(),
+                ),
+                Err(_) => fail.context(StrContext::Label(flags_label(flags))),
+            }
+            .map(move |(bank_views, banks)| (flags, bank_views, banks))
+        })
+    });
+
+    (
+        u16(endianness),
+        u16(endianness),
+        u32(endianness),
+        u32(endianness),
+        flags_and_bank_views,
+    )
+        .with_taken()
+        .map(
+            |((id, trigger_mask, serial_number, timestamp, (flags, bank_views, banks)), bytes)| {
+                RawEvent {
+                    id,
+                    trigger_mask,
+                    serial_number,
+                    timestamp,
+                    flags,
+                    bank_views,
+                    banks,
+                    bytes,
+                }
+            },
+        )
+}
+
+pub(crate) fn event_view<'a>(
+    endianness: Endianness,
+    options: FileViewOptions,
+) -> impl Parser<&'a [u8], EventView<'a>, ContextError> {
+    raw_event(endianness, options).map(move |event: RawEvent<Vec<_>>| EventView {
+        id: event.id,
+        trigger_mask: event.trigger_mask,
+        serial_number: event.serial_number,
+        timestamp: event.timestamp,
+        flags: event.flags,
+        bank_views: event.bank_views.into_boxed_slice(),
+        bytes: event.bytes,
+        byte_offset: 0,
+        endianness,
+    })
 }
 
-const BOR_ID: u16 = 0x8000;
 const BOR_ID_SWAPPED: u16 = BOR_ID.swap_bytes();
-const EOR_ID: u16 = 0x8001;
-const MAGIC: u16 = 0x494D;
 
-pub(crate) fn endianness(input: &mut &[u8]) -> PResult<Endianness> {
+// Zero or more events, recording their byte offset from `start`. Events end
+// at the end of the input or at the header of the final ODB dump; anything
+// else has to be a valid event, so the error within it is reported without
+// backtracking.
+fn event_views<'a>(
+    endianness: Endianness,
+    start: &'a [u8],
+    options: FileViewOptions,
+) -> impl Parser<&'a [u8], Box<[EventView<'a>]>, ContextError> {
+    let end = move |input: &mut &'a [u8]| {
+        if input.is_empty() || is_final_header(endianness, input) {
+            Ok(())
+        } else {
+            fail.parse_next(input)
+        }
+    };
+    repeat(
+        0..,
+        preceded(not(end), cut_err(event_view(endianness, options))),
+    )
+    .map(move |mut event_views: Vec<EventView>| {
+        for event_view in &mut event_views {
+            event_view.byte_offset = event_view.bytes.offset_from(&start);
+        }
+        event_views.into_boxed_slice()
+    })
+}
+
+pub(crate) fn endianness(input: &mut &[u8]) -> ModalResult<Endianness> {
     dispatch! {le_u16;
         BOR_ID => empty.value(Endianness::Little),
         BOR_ID_SWAPPED => empty.value(Endianness::Big),
@@ -130,32 +309,264 @@ pub(crate) fn endianness(input: &mut &[u8]) -> PResult<Endianness> {
     .parse_next(input)
 }
 
-pub(crate) fn file_view<'a>(input: &mut &'a [u8]) -> PResult<FileView<'a>> {
+pub(crate) fn file_view<'a>(
+    options: FileViewOptions,
+) -> impl Parser<&'a [u8], FileView<'a>, ContextError> {
+    move |input: &mut &'a [u8]| {
+        let start = *input;
+        let endianness = endianness
+            .context(StrContext::Label("begin-of-run id"))
+            .parse_next(input)?;
+
+        let (run_number, initial_timestamp, initial_odb, event_views) = seq! {(
+            _: u16(endianness).verify(|&magic| magic == MAGIC)
+                .context(StrContext::Label("initial magic marker")),
+            u32(endianness)
+                .context(StrContext::Label("initial run number")),
+            u32(endianness)
+                .context(StrContext::Label("initial unix timestamp")),
+            length_take(u32(endianness))
+                .context(StrContext::Label("initial odb dump")),
+            event_views(endianness, start, options),
+        )}
+        .parse_next(input)?;
+
+        let end = if input.is_empty() && !options.require_final_odb {
+            None
+        } else {
+            Some(
+                seq! {(
+                    _: u16(endianness).verify(|&eor_id| eor_id == EOR_ID)
+                        .context(StrContext::Label("end-of-run id")),
+                    _: u16(endianness).verify(|&magic| magic == MAGIC)
+                        .context(StrContext::Label("final magic marker")),
+                    u32(endianness)
+                        .verify(|&n| n == run_number || !options.require_run_number_match)
+                        .context(StrContext::Label("final run number")),
+                    u32(endianness)
+                        .context(StrContext::Label("final unix timestamp")),
+                    length_take(u32(endianness))
+                        .context(StrContext::Label("final odb dump")),
+                )}
+                .parse_next(input)?,
+            )
+        };
+        end_of_file.parse_next(input)?;
+        let (final_run_number, final_timestamp, final_odb) = end.unwrap_or_default();
+
+        Ok(FileView {
+            run_number,
+            initial_timestamp,
+            initial_odb,
+            event_views,
+            final_run_number,
+            final_timestamp,
+            final_odb,
+            has_final_odb: end.is_some(),
+            endianness,
+        })
+    }
+}
+
+// Parse the initial ODB dump and as many complete events as possible. The
+// final ODB dump is parsed only if it is valid; otherwise, the input is left
+// right after the last complete event.
+pub(crate) fn file_view_lenient<'a>(input: &mut &'a [u8]) -> ModalResult<FileView<'a>> {
+    let start = *input;
+    let (endianness, run_number, initial_timestamp, initial_odb_size) =
+        initial_header.parse_next(input)?;
+    let initial_odb = take(initial_odb_size)
+        .context(StrContext::Label("initial odb dump"))
+        .parse_next(input)?;
+    let mut event_views = Vec::new();
+    // Same as the strict parser, the final header ends the events even if it
+    // (together with the final ODB dump) happens to look like a valid event.
+    while !is_final_header(endianness, input) {
+        let Ok((remaining, mut event_view)) =
+            event_view(endianness, FileViewOptions::new()).parse_peek(*input)
+        else {
+            break;
+        };
+        event_view.byte_offset = event_view.bytes.offset_from(&start);
+        event_views.push(event_view);
+        *input = remaining;
+    }
+    let end = opt(final_header_unchecked(endianness).flat_map(
+        |(final_run_number, final_timestamp, final_odb_size)| {
+            take(final_odb_size)
+                .map(move |final_odb| (final_run_number, final_timestamp, final_odb))
+        },
+    ))
+    .parse_next(input)?;
+    let (final_run_number, final_timestamp, final_odb) = end.unwrap_or_default();
+
+    Ok(FileView {
+        run_number,
+        initial_timestamp,
+        initial_odb,
+        event_views: event_views.into_boxed_slice(),
+        final_run_number,
+        final_timestamp,
+        final_odb,
+        has_final_odb: end.is_some(),
+        endianness,
+    })
+}
+
+// Parse a complete input that starts at `offset` bytes from the beginning of
+// the file.
+pub(crate) fn parse_at<'a, O>(
+    mut parser: impl Parser<&'a [u8], O, ContextError>,
+    input: &'a [u8],
+    offset: usize,
+) -> Result<O, ParseError> {
+    parser.parse(input).map_err(|e| ParseError {
+        offset: offset + e.offset(),
+        inner: e.into_inner(),
+        event_index: None,
+        bank_name: None,
+    })
+}
+
+// Header of the initial ODB dump: endianness, run number, unix timestamp, and
+// size of the ODB dump.
+pub(crate) fn initial_header(input: &mut &[u8]) -> ModalResult<(Endianness, u32, u32, u32)> {
     let endianness = endianness
         .context(StrContext::Label("begin-of-run id"))
         .parse_next(input)?;
 
-    seq! {FileView{
+    seq! {(
+        empty.value(endianness),
         _: u16(endianness).verify(|&magic| magic == MAGIC)
             .context(StrContext::Label("initial magic marker")),
-        run_number: u32(endianness)
+        u32(endianness)
             .context(StrContext::Label("initial run number")),
-        initial_timestamp: u32(endianness)
+        u32(endianness)
             .context(StrContext::Label("initial unix timestamp")),
-        initial_odb: length_take(u32(endianness))
+        u32(endianness)
             .context(StrContext::Label("initial odb dump")),
-        event_views: repeat(0.., event_view(endianness))
-            .map(|event_views: Vec<_>| event_views.into_boxed_slice()),
+    )}
+    .parse_next(input)
+}
+
+// Header of the final ODB dump: unix timestamp and size of the ODB dump.
+pub(crate) fn final_header<'a>(
+    endianness: Endianness,
+    run_number: u32,
+) -> impl Parser<&'a [u8], (u32, u32), ContextError> {
+    seq! {(
         _: u16(endianness).verify(|&eor_id| eor_id == EOR_ID)
             .context(StrContext::Label("end-of-run id")),
         _: u16(endianness).verify(|&magic| magic == MAGIC)
             .context(StrContext::Label("final magic marker")),
-        _: u32(endianness).verify(|&n| n == run_number)
+        _: u32(endianness).verify(move |&n| n == run_number)
             .context(StrContext::Label("final run number")),
-        final_timestamp: u32(endianness)
+        u32(endianness)
             .context(StrContext::Label("final unix timestamp")),
-        final_odb: length_take(u32(endianness))
+        u32(endianness)
             .context(StrContext::Label("final odb dump")),
-    }}
-    .parse_next(input)
+    )}
+}
+
+// Header of the final ODB dump, without checking that its run number matches
+// the initial one: run number, unix timestamp, and size of the ODB dump.
+fn final_header_unchecked<'a>(
+    endianness: Endianness,
+) -> impl Parser<&'a [u8], (u32, u32, u32), ContextError> {
+    seq! {(
+        _: u16(endianness).verify(|&eor_id| eor_id == EOR_ID)
+            .context(StrContext::Label("end-of-run id")),
+        _: u16(endianness).verify(|&magic| magic == MAGIC)
+            .context(StrContext::Label("final magic marker")),
+        u32(endianness)
+            .context(StrContext::Label("final run number")),
+        u32(endianness)
+            .context(StrContext::Label("final unix timestamp")),
+        u32(endianness)
+            .context(StrContext::Label("final odb dump")),
+    )}
+}
+
+// Returns `true` if the 4 bytes at the start of the input are the end-of-run
+// id followed by the magic marker.
+// No bytes are allowed after the final ODB dump.
+pub(crate) fn end_of_file(input: &mut &[u8]) -> ModalResult<()> {
+    eof.void()
+        .context(StrContext::Label("end of file"))
+        .parse_next(input)
+}
+
+pub(crate) fn is_final_header(endianness: Endianness, input: &[u8]) -> bool {
+    (u16(endianness), u16(endianness))
+        .parse_peek(input)
+        .is_ok_and(|(_, (id, magic))| id == EOR_ID && magic == MAGIC)
+}
+
+// Name of the first data bank in the (invalid) event at the start of the input
+// that fails to parse.
+pub(crate) fn invalid_bank_name(
+    endianness: Endianness,
+    input: &[u8],
+    max_data_size: u32,
+) -> Option<[u8; 4]> {
+    let (mut banks, (_, _, _, _, event_size, banks_size, flags)) = (
+        u16(endianness),
+        u16(endianness),
+        u32(endianness),
+        u32(endianness),
+        u32(endianness),
+        u32(endianness),
+        u32(endianness),
+    )
+        .parse_peek(input)
+        .ok()?;
+    // The banks cannot be located if the sizes in the event header are
+    // themselves inconsistent.
+    if event_size.checked_sub(8) != Some(banks_size) {
+        return None;
+    }
+    banks = &banks[..banks
+        .len()
+        .min(usize::try_from(banks_size).unwrap_or(usize::MAX))];
+    BankType::try_from(flags).ok()?;
+
+    while !banks.is_empty() {
+        match bank_view(endianness, flags, max_data_size).parse_peek(banks) {
+            Ok((remaining, _)) => banks = remaining,
+            Err(_) => return banks.get(..4).and_then(|name| name.try_into().ok()),
+        }
+    }
+    None
+}
+
+// Index of the invalid event, and name of the invalid data bank within it, in
+// a file that failed to parse. Both are `None` if the error is not within the
+// events.
+pub(crate) fn locate_error(
+    mut input: &[u8],
+    options: FileViewOptions,
+) -> (Option<usize>, Option<[u8; 4]>) {
+    let Ok((endianness, _, _, initial_odb_size)) = initial_header.parse_next(&mut input) else {
+        return (None, None);
+    };
+    if take::<_, _, ContextError>(initial_odb_size)
+        .parse_next(&mut input)
+        .is_err()
+    {
+        return (None, None);
+    }
+
+    let mut event_index = 0;
+    while let Ok((remaining, _)) = event_view(endianness, options).parse_peek(input) {
+        input = remaining;
+        event_index += 1;
+    }
+    if input.is_empty() || is_final_header(endianness, input) {
+        (None, None)
+    } else {
+        (
+            Some(event_index),
+            invalid_bank_name(endianness, input, options.max_bank_data_size),
+        )
+    }
 }
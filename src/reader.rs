@@ -0,0 +1,226 @@
+use crate::{parse, Endianness, FileViewOptions, OwnedEvent, ParseError};
+use std::fmt;
+use std::io::Read;
+use winnow::Parser;
+
+/// The error type returned when reading a MIDAS file with an [`EventReader`]
+/// or [`File::from_reader`](crate::File::from_reader) fails.
+#[derive(Debug)]
+pub enum ReadError {
+    /// An I/O error from the underlying reader. A file that ends unexpectedly
+    /// is reported as an [`std::io::ErrorKind::UnexpectedEof`] error.
+    Io(std::io::Error),
+    /// The bytes read are not a valid MIDAS file.
+    Parse(ParseError),
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::Io(_) => write!(f, "failed to read midas file"),
+            ReadError::Parse(_) => write!(f, "failed to parse midas file"),
+        }
+    }
+}
+
+impl std::error::Error for ReadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ReadError::Io(e) => Some(e),
+            ReadError::Parse(e) => Some(e),
+        }
+    }
+}
+
+impl From<std::io::Error> for ReadError {
+    fn from(e: std::io::Error) -> Self {
+        ReadError::Io(e)
+    }
+}
+
+impl From<ParseError> for ReadError {
+    fn from(e: ParseError) -> Self {
+        ReadError::Parse(e)
+    }
+}
+
+/// A streaming reader of MIDAS files.
+///
+/// Unlike [`FileView`](crate::FileView), which requires the complete file in
+/// memory, an `EventReader` pulls bytes from an [`std::io::Read`] and holds only
+/// one event at a time. The initial ODB dump is read when the reader is
+/// created, and the final ODB dump is available once all the events have been
+/// read.
+///
+/// The reader does not do any buffering of its own. Wrap unbuffered sources
+/// (e.g. a [`std::fs::File`]) in a [`std::io::BufReader`].
+///
+/// The number of events is not known until the end of the file is reached, so
+/// the reader does not implement [`ExactSizeIterator`]. Events can only be
+/// read in order, so it does not implement [`DoubleEndedIterator`] either;
+/// use [`FileView::iter_rev`](crate::FileView::iter_rev) to iterate in reverse.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::io::BufReader;
+/// use midasio::EventReader;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let file = std::fs::File::open("example.mid")?;
+/// let mut reader = EventReader::new(BufReader::new(file))?;
+///
+/// for event in &mut reader {
+///     let event = event?;
+///     for bank in &event.view() {
+///         // Process the data banks.
+///     }
+/// }
+/// let final_odb = reader.final_odb().unwrap();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct EventReader<R> {
+    reader: R,
+    // Number of bytes read so far.
+    offset: usize,
+    // Number of events read so far.
+    event_count: usize,
+    endianness: Endianness,
+    run_number: u32,
+    initial_timestamp: u32,
+    initial_odb: Vec<u8>,
+    // Final unix timestamp and ODB dump. Set once the end of the file has
+    // been reached successfully.
+    end: Option<(u32, Vec<u8>)>,
+    // Set after the final ODB dump is read or after any error.
+    done: bool,
+}
+
+impl<R: Read> EventReader<R> {
+    /// Creates a new reader, reading the initial ODB dump from the input.
+    pub fn new(mut reader: R) -> Result<Self, ReadError> {
+        let mut header = [0; 16];
+        reader.read_exact(&mut header)?;
+        let (endianness, run_number, initial_timestamp, odb_size) =
+            parse::parse_at(parse::initial_header, &header, 0)?;
+        let initial_odb = read_vec(&mut reader, odb_size)?;
+
+        Ok(EventReader {
+            reader,
+            offset: header.len() + initial_odb.len(),
+            event_count: 0,
+            endianness,
+            run_number,
+            initial_timestamp,
+            initial_odb,
+            end: None,
+            done: false,
+        })
+    }
+    /// Returns the endianness detected from the begin-of-run marker.
+    pub fn endianness(&self) -> Endianness {
+        self.endianness
+    }
+    /// Returns the run number of the file.
+    pub fn run_number(&self) -> u32 {
+        self.run_number
+    }
+    /// Returns the unix timestamp of the initial ODB dump.
+    pub fn initial_timestamp(&self) -> u32 {
+        self.initial_timestamp
+    }
+    /// Returns the initial ODB dump.
+    pub fn initial_odb(&self) -> &[u8] {
+        &self.initial_odb
+    }
+    /// Returns the unix timestamp of the final ODB dump, or [`None`] if the
+    /// end of the file has not been reached yet.
+    pub fn final_timestamp(&self) -> Option<u32> {
+        self.end.as_ref().map(|(timestamp, _)| *timestamp)
+    }
+    /// Returns the final ODB dump, or [`None`] if the end of the file has not
+    /// been reached yet.
+    pub fn final_odb(&self) -> Option<&[u8]> {
+        self.end.as_ref().map(|(_, odb)| &odb[..])
+    }
+
+    // Returns `None` after reading the final ODB dump.
+    fn read_next(&mut self) -> Result<Option<OwnedEvent>, ReadError> {
+        // Both an event header (up to the event size) and the final ODB dump
+        // header are 16 bytes long.
+        let mut header = [0; 16];
+        self.reader.read_exact(&mut header)?;
+
+        if parse::is_final_header(self.endianness, &header) {
+            let (final_timestamp, odb_size) = parse::parse_at(
+                parse::final_header(self.endianness, self.run_number),
+                &header,
+                self.offset,
+            )?;
+            let final_odb = read_vec(&mut self.reader, odb_size)?;
+            self.offset += header.len() + final_odb.len();
+            let mut trailing = [0];
+            let len = self.reader.read(&mut trailing)?;
+            parse::parse_at(parse::end_of_file, &trailing[..len], self.offset)?;
+
+            self.end = Some((final_timestamp, final_odb));
+            Ok(None)
+        } else {
+            let event_size = parse::u32(self.endianness)
+                .parse_peek(&header[12..])
+                .unwrap()
+                .1;
+            let mut bytes = header.to_vec();
+            bytes.extend(read_vec(&mut self.reader, event_size)?);
+            parse::parse_at(
+                parse::event_view(self.endianness, FileViewOptions::new()),
+                &bytes,
+                self.offset,
+            )
+            .map_err(|e| ParseError {
+                event_index: Some(self.event_count),
+                bank_name: parse::invalid_bank_name(self.endianness, &bytes, u32::MAX),
+                ..e
+            })?;
+            let byte_offset = self.offset;
+            self.offset += bytes.len();
+            self.event_count += 1;
+
+            Ok(Some(OwnedEvent {
+                bytes: bytes.into_boxed_slice(),
+                byte_offset,
+                endianness: self.endianness,
+            }))
+        }
+    }
+}
+
+impl<R: Read> Iterator for EventReader<R> {
+    type Item = Result<OwnedEvent, ReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let result = self.read_next();
+        if !matches!(result, Ok(Some(_))) {
+            self.done = true;
+        }
+        result.transpose()
+    }
+}
+
+impl<R: Read> std::iter::FusedIterator for EventReader<R> {}
+
+// Read exactly `len` bytes. The buffer grows as bytes are read, so a corrupted
+// size does not allocate a huge buffer up front.
+fn read_vec<R: Read>(reader: &mut R, len: u32) -> std::io::Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    reader.by_ref().take(len.into()).read_to_end(&mut bytes)?;
+    if usize::try_from(len) != Ok(bytes.len()) {
+        return Err(std::io::ErrorKind::UnexpectedEof.into());
+    }
+    Ok(bytes)
+}
@@ -0,0 +1,360 @@
+//! Utilities to write binary MIDAS files.
+//!
+//! # Examples
+//!
+//! ```
+//! use midasio::write::{EventBuilder, FileBuilder};
+//! use midasio::DataType;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let bytes = FileBuilder::new(1)
+//!     .initial_odb(b"initial odb".to_vec())
+//!     .event(
+//!         EventBuilder::new(1)
+//!             .serial_number(0)
+//!             .bank(*b"ADC0", DataType::U16, vec![1, 0, 2, 0]),
+//!     )
+//!     .final_odb(b"final odb".to_vec())
+//!     .build()?;
+//!
+//! let file_view = midasio::FileView::try_from_bytes(&bytes)?;
+//! assert_eq!(file_view.run_number(), 1);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::constants::{BANK_DATA_ALIGNMENT, BOR_ID, EOR_ID, MAGIC};
+use crate::{BankType, DataType, Endianness, EventView, OwnedEvent};
+use alloc::vec::Vec;
+
+/// The error type returned when writing a MIDAS file fails.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum WriteError {
+    /// The length of the data in a bank is not a multiple of the size of its
+    /// data type.
+    InvalidBankData {
+        /// Name of the data bank.
+        name: [u8; 4],
+        /// Data type of the data bank.
+        data_type: DataType,
+        /// Length in bytes of the data.
+        len: usize,
+    },
+    /// A data bank, event, or ODB dump is too large to be represented in the
+    /// MIDAS format.
+    TooLarge,
+}
+
+impl core::fmt::Display for WriteError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            WriteError::InvalidBankData {
+                name,
+                data_type,
+                len,
+            } => write!(
+                f,
+                "data of bank `{}` has `{len}` bytes, which is not a multiple of the size of `{data_type:?}`",
+                name.escape_ascii()
+            ),
+            WriteError::TooLarge => write!(f, "size exceeds the limits of the MIDAS format"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for WriteError {}
+
+pub(crate) fn tid(data_type: DataType) -> u16 {
+    match data_type {
+        DataType::U8 => 1,
+        DataType::I8 => 2,
+        DataType::U16 => 4,
+        DataType::I16 => 5,
+        DataType::U32 => 6,
+        DataType::I32 => 7,
+        DataType::Bool => 8,
+        DataType::F32 => 9,
+        DataType::F64 => 10,
+        DataType::Str => 12,
+        DataType::Array => 13,
+        DataType::Struct => 14,
+        DataType::I64 => 17,
+        DataType::U64 => 18,
+        DataType::Key => 15,
+        DataType::Link => 16,
+    }
+}
+
+struct Writer {
+    endianness: Endianness,
+    bytes: Vec<u8>,
+}
+
+impl Writer {
+    fn u16(&mut self, n: u16) {
+        match self.endianness {
+            Endianness::Little => self.bytes.extend(n.to_le_bytes()),
+            Endianness::Big => self.bytes.extend(n.to_be_bytes()),
+        }
+    }
+
+    fn u32(&mut self, n: u32) {
+        match self.endianness {
+            Endianness::Little => self.bytes.extend(n.to_le_bytes()),
+            Endianness::Big => self.bytes.extend(n.to_be_bytes()),
+        }
+    }
+
+    fn len_u32(&mut self, len: usize) -> Result<(), WriteError> {
+        self.u32(u32::try_from(len).map_err(|_| WriteError::TooLarge)?);
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Bank {
+    name: [u8; 4],
+    data_type: DataType,
+    data: Vec<u8>,
+}
+
+/// A builder for an event in a MIDAS file.
+///
+/// All the header fields default to zero.
+#[derive(Clone, Debug)]
+pub struct EventBuilder {
+    id: u16,
+    trigger_mask: u16,
+    serial_number: u32,
+    timestamp: u32,
+    banks: Vec<Bank>,
+}
+
+impl EventBuilder {
+    /// Creates a new builder for an event with the given ID and no data
+    /// banks.
+    pub fn new(id: u16) -> Self {
+        EventBuilder {
+            id,
+            trigger_mask: 0,
+            serial_number: 0,
+            timestamp: 0,
+            banks: Vec::new(),
+        }
+    }
+    /// Sets the trigger mask of the event.
+    pub fn trigger_mask(mut self, trigger_mask: u16) -> Self {
+        self.trigger_mask = trigger_mask;
+        self
+    }
+    /// Sets the serial number of the event.
+    pub fn serial_number(mut self, serial_number: u32) -> Self {
+        self.serial_number = serial_number;
+        self
+    }
+    /// Sets the unix timestamp of the event.
+    pub fn timestamp(mut self, timestamp: u32) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+    /// Appends a data bank to the event.
+    ///
+    /// The data has to be already encoded in the byte order of the file.
+    pub fn bank(mut self, name: [u8; 4], data_type: DataType, data: Vec<u8>) -> Self {
+        self.banks.push(Bank {
+            name,
+            data_type,
+            data,
+        });
+        self
+    }
+    /// Writes the event in the given byte order into an [`OwnedEvent`].
+    ///
+    /// This is useful to rewrite events with [`transform`](crate::transform).
+    /// The [`OwnedEvent::byte_offset`] of the returned event is zero.
+    pub fn build(&self, endianness: Endianness) -> Result<OwnedEvent, WriteError> {
+        let mut writer = Writer {
+            endianness,
+            bytes: Vec::new(),
+        };
+        self.write(&mut writer)?;
+        Ok(OwnedEvent {
+            bytes: writer.bytes.into(),
+            byte_offset: 0,
+            endianness,
+        })
+    }
+
+    fn write(&self, writer: &mut Writer) -> Result<(), WriteError> {
+        for bank in &self.banks {
+            if !bank
+                .data
+                .len()
+                .is_multiple_of(bank.data_type.size().unwrap_or(1))
+            {
+                return Err(WriteError::InvalidBankData {
+                    name: bank.name,
+                    data_type: bank.data_type,
+                    len: bank.data.len(),
+                });
+            }
+        }
+        // Use the smallest bank headers whenever possible, and fall back to
+        // 32-bit aligned banks for large data banks.
+        let bank_type = if self
+            .banks
+            .iter()
+            .all(|bank| bank.data.len() <= usize::from(u16::MAX))
+        {
+            BankType::B16
+        } else {
+            BankType::B32A
+        };
+        let header_size = bank_type.header_size();
+        let banks_size = self.banks.iter().try_fold(0usize, |acc, bank| {
+            acc.checked_add(header_size + bank.data.len().next_multiple_of(BANK_DATA_ALIGNMENT))
+        });
+        let banks_size = banks_size
+            .and_then(|n| u32::try_from(n).ok())
+            .filter(|n| n.checked_add(8).is_some())
+            .ok_or(WriteError::TooLarge)?;
+
+        writer.u16(self.id);
+        writer.u16(self.trigger_mask);
+        writer.u32(self.serial_number);
+        writer.u32(self.timestamp);
+        writer.u32(banks_size + 8);
+        writer.u32(banks_size);
+        writer.u32(bank_type.to_flag());
+        for bank in &self.banks {
+            writer.bytes.extend(bank.name);
+            if bank_type == BankType::B16 {
+                writer.u16(tid(bank.data_type));
+                writer.u16(bank.data.len() as u16);
+            } else {
+                writer.u32(tid(bank.data_type).into());
+                writer.len_u32(bank.data.len())?;
+                writer.u32(0);
+            }
+            writer.bytes.extend(&bank.data);
+            let padding = bank.data.len().next_multiple_of(BANK_DATA_ALIGNMENT) - bank.data.len();
+            writer.bytes.extend(core::iter::repeat_n(0, padding));
+        }
+        Ok(())
+    }
+}
+
+impl From<&EventView<'_>> for EventBuilder {
+    fn from(event_view: &EventView<'_>) -> Self {
+        EventBuilder {
+            id: event_view.id(),
+            trigger_mask: event_view.trigger_mask(),
+            serial_number: event_view.serial_number(),
+            timestamp: event_view.timestamp(),
+            banks: event_view
+                .iter()
+                .map(|bank_view| Bank {
+                    name: bank_view.name(),
+                    data_type: bank_view.data_type(),
+                    data: bank_view.data().to_vec(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// A builder for a MIDAS file.
+///
+/// Event sizes, bank sizes, and padding are computed automatically. Events in
+/// which all the data banks are smaller than 64 KiB are written with 16-bit
+/// bank headers; otherwise, 32-bit aligned bank headers are used. By default,
+/// the file is written in little-endian byte order, with zero timestamps and
+/// empty ODB dumps.
+#[derive(Clone, Debug)]
+pub struct FileBuilder {
+    endianness: Endianness,
+    run_number: u32,
+    initial_timestamp: u32,
+    initial_odb: Vec<u8>,
+    events: Vec<EventBuilder>,
+    final_timestamp: u32,
+    final_odb: Vec<u8>,
+}
+
+impl FileBuilder {
+    /// Creates a new builder for a file with the given run number and no
+    /// events.
+    pub fn new(run_number: u32) -> Self {
+        FileBuilder {
+            endianness: Endianness::Little,
+            run_number,
+            initial_timestamp: 0,
+            initial_odb: Vec::new(),
+            events: Vec::new(),
+            final_timestamp: 0,
+            final_odb: Vec::new(),
+        }
+    }
+    /// Sets the byte order of the file.
+    pub fn endianness(mut self, endianness: Endianness) -> Self {
+        self.endianness = endianness;
+        self
+    }
+    /// Sets the unix timestamp of the initial ODB dump.
+    pub fn initial_timestamp(mut self, timestamp: u32) -> Self {
+        self.initial_timestamp = timestamp;
+        self
+    }
+    /// Sets the initial ODB dump.
+    pub fn initial_odb(mut self, odb: Vec<u8>) -> Self {
+        self.initial_odb = odb;
+        self
+    }
+    /// Appends an event to the file.
+    pub fn event(mut self, event: EventBuilder) -> Self {
+        self.events.push(event);
+        self
+    }
+    /// Appends multiple events to the file.
+    pub fn events<I: IntoIterator<Item = EventBuilder>>(mut self, events: I) -> Self {
+        self.events.extend(events);
+        self
+    }
+    /// Sets the unix timestamp of the final ODB dump.
+    pub fn final_timestamp(mut self, timestamp: u32) -> Self {
+        self.final_timestamp = timestamp;
+        self
+    }
+    /// Sets the final ODB dump.
+    pub fn final_odb(mut self, odb: Vec<u8>) -> Self {
+        self.final_odb = odb;
+        self
+    }
+    /// Returns the binary representation of the MIDAS file.
+    pub fn build(&self) -> Result<Vec<u8>, WriteError> {
+        let mut writer = Writer {
+            endianness: self.endianness,
+            bytes: Vec::new(),
+        };
+
+        writer.u16(BOR_ID);
+        writer.u16(MAGIC);
+        writer.u32(self.run_number);
+        writer.u32(self.initial_timestamp);
+        writer.len_u32(self.initial_odb.len())?;
+        writer.bytes.extend(&self.initial_odb);
+        for event in &self.events {
+            event.write(&mut writer)?;
+        }
+        writer.u16(EOR_ID);
+        writer.u16(MAGIC);
+        writer.u32(self.run_number);
+        writer.u32(self.final_timestamp);
+        writer.len_u32(self.final_odb.len())?;
+        writer.bytes.extend(&self.final_odb);
+
+        Ok(writer.bytes)
+    }
+}